@@ -1,12 +0,0 @@
-use crate::{Solution, SolutionPair};
-use std::fs::read_to_string;
-
-///////////////////////////////////////////////////////////////////////////////
-
-pub fn solve() -> SolutionPair {
-    // Your solution here...
-    let sol1 = 0;
-    let sol2 = 0;
-
-    (Solution::from(sol1), Solution::from(sol2))
-}
@@ -0,0 +1,379 @@
+use crate::etc::cli::Part;
+use crate::etc::input::with_legacy_fallback;
+use crate::Solution;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fs::{self, read_to_string, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The recorded answers of a day, as stored in `answers/YYYY/dayNN.txt`.
+///
+/// Each answer is on its own line, prefixed by its part number:
+///
+/// ```text
+/// # Comments and blank lines are ignored
+/// 1: 2580760
+/// 2: 25358365
+/// ```
+///
+/// A part without a line has no recorded answer yet. An answer drawn over several lines, such as
+/// ASCII art, continues on the lines that follow its part number, each prefixed by `|` and a
+/// space:
+///
+/// ```text
+/// 2:
+/// | #..#.####
+/// | ####.#...
+/// ```
+///
+/// With the `serde` feature, the answers may be recorded in TOML in `answers/YYYY/dayNN.toml`
+/// instead, which is then used over the text file:
+///
+/// ```toml
+/// part1 = 2580760
+/// part2 = """
+/// #..#.####
+/// ####.#..."""
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "AnswersFile", into = "AnswersFile")
+)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+// The serialized form of the answers, where a part without an answer is left out
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnswersFile {
+    part1: Solution,
+    part2: Solution,
+}
+
+#[cfg(feature = "serde")]
+impl From<AnswersFile> for Answers {
+    fn from(file: AnswersFile) -> Self {
+        let answer = |solution: Solution| (!solution.is_unsolved()).then(|| solution.to_string());
+        Answers {
+            part1: answer(file.part1),
+            part2: answer(file.part2),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Answers> for AnswersFile {
+    fn from(answers: Answers) -> Self {
+        let solution = |answer: Option<String>| match answer {
+            Some(answer) => Solution::Str(answer),
+            None => Solution::Unsolved,
+        };
+        AnswersFile {
+            part1: solution(answers.part1),
+            part2: solution(answers.part2),
+        }
+    }
+}
+
+// Prefixes the lines of a multi-line answer
+const CONTINUATION: &str = "|";
+
+/// The outcome of comparing a computed answer against the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There is no recorded answer, but the answer is known to be wrong from an earlier submission.
+    Rejected {
+        reason: String,
+    },
+    Unknown,
+}
+
+/// Where the answers of a day are recorded under `root`, including the legacy layout.
+pub fn answers_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    let path = with_legacy_fallback(
+        root.join(format!("answers/{}/day{:02}.txt", year, day)),
+        year,
+        || root.join(format!("answers/day{:02}.txt", day)),
+    );
+    #[cfg(feature = "serde")]
+    if path.with_extension("toml").exists() {
+        return path.with_extension("toml");
+    }
+    path
+}
+
+#[cfg(feature = "serde")]
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "toml")
+}
+
+/// Reads the recorded answers of a day. A missing answers file means nothing is recorded yet.
+pub fn load_answers(year: u16, day: u8) -> io::Result<Answers> {
+    read_answers(&answers_path(Path::new(""), year, day))
+}
+
+fn read_answers(path: &Path) -> io::Result<Answers> {
+    let text = match read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+        Err(err) => return Err(err),
+    };
+    #[cfg(feature = "serde")]
+    let answers = match is_toml(path) {
+        true => toml::from_str(&text).map_err(|err| err.message().to_owned()),
+        false => Answers::parse(&text),
+    };
+    #[cfg(not(feature = "serde"))]
+    let answers = Answers::parse(&text);
+    answers.map_err(|msg| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), msg),
+        )
+    })
+}
+
+/// Records the accepted answer to a part in the answers file of its day, creating it if needed.
+pub fn record_answer(year: u16, day: u8, part: Part, answer: &str) -> io::Result<()> {
+    write_answer(&answers_path(Path::new(""), year, day), part, answer)
+}
+
+fn write_answer(path: &Path, part: Part, answer: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // A TOML file is rewritten as a whole
+    #[cfg(feature = "serde")]
+    if is_toml(path) {
+        let mut answers = read_answers(path)?;
+        answers.set(part, answer.to_owned());
+        return fs::write(
+            path,
+            toml::to_string(&answers).expect("answers are serializable"),
+        );
+    }
+
+    let text = match read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if !text.is_empty() && !text.ends_with('\n') {
+        writeln!(file)?;
+    }
+    write!(file, "{}", format_answer(part, answer))
+}
+
+/// The lines recording an answer to a part, as in an answers file. A multi-line answer starts on
+/// the line after the part number, with each of its lines prefixed by `|`.
+pub fn format_answer(part: Part, answer: &str) -> String {
+    if !answer.contains('\n') {
+        return format!("{}: {}\n", part, answer);
+    }
+    let mut text = format!("{}:\n", part);
+    for line in answer.split('\n') {
+        match line {
+            "" => text.push_str(CONTINUATION),
+            line => text.push_str(&format!("{} {}", CONTINUATION, line)),
+        }
+        text.push('\n');
+    }
+    text
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut last = None;
+
+        for (number, line) in text.lines().enumerate() {
+            if let Some(rest) = line.strip_prefix(CONTINUATION) {
+                let rest = rest
+                    .strip_prefix(' ')
+                    .unwrap_or(rest)
+                    .trim_end_matches('\r');
+                let Some(answer) = last.and_then(|part| answers.slot(part).as_mut()) else {
+                    return Err(format!(
+                        "line {}: a `{}` line must follow the answer it continues",
+                        number + 1,
+                        CONTINUATION
+                    ));
+                };
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(rest);
+                continue;
+            }
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                last = None;
+                continue;
+            }
+
+            let (part, answer) = match line.split_once(':') {
+                Some(("1", answer)) => (Part::One, answer),
+                Some(("2", answer)) => (Part::Two, answer),
+                _ => {
+                    return Err(format!(
+                        "line {}: expected `1: <answer>` or `2: <answer>`",
+                        number + 1
+                    ))
+                }
+            };
+            *answers.slot(part) = Some(answer.trim().to_owned());
+            last = Some(part);
+        }
+
+        Ok(answers)
+    }
+
+    fn slot(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        *self.slot(part) = Some(answer);
+    }
+
+    pub fn verify(&self, part: Part, solution: &Solution) -> Verdict {
+        match self.get(part) {
+            _ if solution.is_unsolved() => Verdict::Unknown,
+            Some(expected) if solution.matches(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("# Day 1\n1: 11\n\n2:  31 \n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("11"));
+        assert_eq!(answers.get(Part::Two), Some("31"));
+
+        let answers = Answers::parse("2: abc").unwrap();
+        assert_eq!(answers.get(Part::One), None);
+
+        assert!(Answers::parse("3: 12").is_err());
+        assert!(Answers::parse("12").is_err());
+    }
+
+    #[test]
+    fn test_multi_line_answers() {
+        let answer = "#..#\n\n.##. ";
+        let mut text = format_answer(Part::One, "11");
+        text.push_str(&format_answer(Part::Two, answer));
+        assert_eq!(text, "1: 11\n2:\n| #..#\n|\n| .##. \n");
+
+        let answers = Answers::parse(&text).unwrap();
+        assert_eq!(answers.get(Part::One), Some("11"));
+        assert_eq!(answers.get(Part::Two), Some(answer));
+        assert_eq!(
+            answers.verify(Part::Two, &answer.parse().unwrap()),
+            Verdict::Pass
+        );
+
+        // The first line may also follow the part number
+        let answers = Answers::parse("2: #..#\r\n|.##.\r\n").unwrap();
+        assert_eq!(answers.get(Part::Two), Some("#..#\n.##."));
+
+        assert!(Answers::parse("| #..#\n1: 11").is_err());
+        assert!(Answers::parse("1: 11\n\n| #..#").is_err());
+    }
+
+    #[test]
+    fn test_write_answer() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let path = dir.join("2024/day06.txt");
+        write_answer(&path, Part::One, "41").unwrap();
+        write_answer(&path, Part::Two, "#..#\n.##.").unwrap();
+        assert_eq!(
+            read_to_string(&path).unwrap(),
+            "1: 41\n2:\n| #..#\n| .##.\n"
+        );
+        assert_eq!(
+            read_answers(&path).unwrap().get(Part::Two),
+            Some("#..#\n.##.")
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let path = path.with_extension("toml");
+            write_answer(&path, Part::Two, "#..#\n.##.").unwrap();
+            write_answer(&path, Part::One, "41").unwrap();
+            let answers = read_answers(&path).unwrap();
+            assert_eq!(answers.get(Part::One), Some("41"));
+            assert_eq!(answers.get(Part::Two), Some("#..#\n.##."));
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_toml_answers() {
+        let answers: Answers =
+            toml::from_str("part1 = 2580760\npart2 = \"\"\"\n#..#\n.##.\"\"\"\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("2580760"));
+        assert_eq!(answers.get(Part::Two), Some("#..#\n.##."));
+
+        let answers = Answers::parse("2: abc").unwrap();
+        assert_eq!(toml::to_string(&answers).unwrap(), "part2 = \"abc\"\n");
+        assert_eq!(
+            toml::from_str::<Answers>("part2 = \"abc\"").unwrap(),
+            answers
+        );
+        assert!(toml::from_str::<Answers>("part3 = 1").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("1: 11\n").unwrap();
+        assert_eq!(
+            answers.verify(Part::One, &Solution::from(11u64)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verify(Part::One, &Solution::from(12)),
+            Verdict::Fail {
+                expected: "11".to_owned()
+            }
+        );
+        assert_eq!(
+            answers.verify(Part::Two, &Solution::from(31)),
+            Verdict::Unknown
+        );
+    }
+}
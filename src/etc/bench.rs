@@ -0,0 +1,139 @@
+use crate::etc::cli::{Part, RunArgs};
+use crate::etc::input::load_input;
+use crate::etc::solver::{elapsed_ms, DaySolver};
+use crate::SolveError;
+use std::time::Instant;
+
+/// Summary statistics over the timings of repeated runs, in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Computes the statistics of a non-empty set of samples.
+    pub fn from_samples(samples: &[f64]) -> Stats {
+        assert!(!samples.is_empty(), "cannot summarize zero samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        // Sample standard deviation, a single run has no spread
+        let stddev = if n > 1 {
+            let variance = sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev,
+        }
+    }
+}
+
+/// Timings of one day: reading its input from disk, parsing it, and each of its selected parts.
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub load: Stats,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl DayBench {
+    /// The median time to parse the input and solve all selected parts, excluding input loading.
+    pub fn solve_median(&self) -> f64 {
+        self.parse.median
+            + self
+                .parts
+                .iter()
+                .map(|(_, stats)| stats.median)
+                .sum::<f64>()
+    }
+}
+
+/// Runs the selected parts of a day `warmup` times without measuring, then `iterations` times
+/// measuring input loading, parsing and each part separately.
+///
+/// Returns `Ok(None)` for days without a solution to any of the selected parts.
+pub fn bench_day(
+    solver: &DaySolver,
+    args: &RunArgs,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Option<DayBench>, SolveError> {
+    let (year, day) = (solver.year, solver.day);
+    let parts: Vec<Part> = args
+        .parts()
+        .into_iter()
+        .filter(|&part| solver.implements(part))
+        .collect();
+    if parts.is_empty() {
+        return Ok(None);
+    }
+
+    for _ in 0..warmup {
+        let input = load_input(year, day, args.input.as_deref())?;
+        solver.solve(&input, &parts)?;
+    }
+
+    let mut load_samples = Vec::with_capacity(iterations);
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = vec![Vec::with_capacity(iterations); parts.len()];
+
+    for _ in 0..iterations {
+        let time = Instant::now();
+        let input = load_input(year, day, args.input.as_deref())?;
+        load_samples.push(elapsed_ms(time));
+
+        let solved = solver.solve(&input, &parts)?;
+        parse_samples.push(solved.parse_ms);
+        for (result, samples) in solved.parts.iter().zip(part_samples.iter_mut()) {
+            samples.push(result.elapsed_ms);
+        }
+    }
+
+    Ok(Some(DayBench {
+        year,
+        day,
+        load: Stats::from_samples(&load_samples),
+        parse: Stats::from_samples(&parse_samples),
+        parts: parts
+            .into_iter()
+            .zip(part_samples)
+            .map(|(part, samples)| (part, Stats::from_samples(&samples)))
+            .collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&[4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.2909944).abs() < 1e-6);
+
+        let stats = Stats::from_samples(&[5.0]);
+        assert_eq!(stats.median, 5.0);
+        assert_eq!(stats.stddev, 0.0);
+    }
+}
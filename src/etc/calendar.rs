@@ -0,0 +1,63 @@
+use crate::etc::cli::LAST_DAY;
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+
+/// Puzzles unlock at midnight US Eastern Time. Daylight saving time is over in December, so that
+/// is always UTC-5.
+pub fn unlock_timezone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).expect("UTC-5 is a valid offset")
+}
+
+/// The latest puzzle unlocked at `now`, as its event year and day. Before December 1st, that is
+/// the last day of the previous year's event.
+pub fn latest_unlocked(now: DateTime<Utc>) -> (u16, u8) {
+    let date = now.with_timezone(&unlock_timezone()).date_naive();
+    if date.month() == 12 {
+        (date.year() as u16, (date.day() as u8).min(LAST_DAY))
+    } else {
+        (date.year() as u16 - 1, LAST_DAY)
+    }
+}
+
+/// The latest day of the `year` event unlocked at `now`, or `None` if the event has not started.
+pub fn latest_unlocked_day(year: u16, now: DateTime<Utc>) -> Option<u8> {
+    let (current_year, day) = latest_unlocked(now);
+    match year.cmp(&current_year) {
+        std::cmp::Ordering::Less => Some(LAST_DAY),
+        std::cmp::Ordering::Equal => Some(day),
+        std::cmp::Ordering::Greater => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_latest_unlocked() {
+        // Already December in UTC, but still November 30th in the US
+        assert_eq!(latest_unlocked(utc("2024-12-01T04:00:00Z")), (2023, 25));
+        assert_eq!(latest_unlocked(utc("2024-11-30T12:00:00Z")), (2023, 25));
+
+        assert_eq!(latest_unlocked(utc("2024-12-01T04:59:59Z")), (2023, 25));
+        assert_eq!(latest_unlocked(utc("2024-12-01T05:00:00Z")), (2024, 1));
+        assert_eq!(latest_unlocked(utc("2024-12-13T04:59:59Z")), (2024, 12));
+
+        assert_eq!(latest_unlocked(utc("2024-12-26T12:00:00Z")), (2024, 25));
+        assert_eq!(latest_unlocked(utc("2025-01-01T12:00:00Z")), (2024, 25));
+    }
+
+    #[test]
+    fn test_latest_unlocked_day() {
+        let now = utc("2024-11-30T12:00:00Z");
+        assert_eq!(latest_unlocked_day(2024, now), None);
+        assert_eq!(latest_unlocked_day(2023, now), Some(25));
+
+        let now = utc("2024-12-06T05:00:00Z");
+        assert_eq!(latest_unlocked_day(2024, now), Some(6));
+        assert_eq!(latest_unlocked_day(2022, now), Some(25));
+    }
+}
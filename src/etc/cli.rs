@@ -0,0 +1,443 @@
+use crate::etc::client::DEFAULT_BASE_URL;
+use crate::etc::history::Baseline;
+use crate::etc::output::Format;
+use crate::etc::solver::years;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Last day of the event, and therefore the highest valid day number.
+pub const LAST_DAY: u8 = 25;
+
+/// The first Advent of Code event.
+pub const FIRST_YEAR: u16 = 2015;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Runner for the Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Running without a subcommand is the same as `run`
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Solve the selected days and print their answers
+    Run(RunArgs),
+    /// Time repeated runs of the selected days and print runtime statistics
+    Bench(BenchArgs),
+    /// Compare the answers of the selected days against the recorded ones
+    Check(RunArgs),
+    /// Generate the module for a new day
+    New {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+
+        /// Event year of the day. Defaults to the latest event that has started.
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_YEAR as i64..))]
+        year: Option<u16>,
+
+        /// Title of the puzzle. Defaults to the title of the day being replaced, if any
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+    /// Download the puzzle inputs of the selected days that are not downloaded yet
+    Fetch(FetchArgs),
+    /// Solve one part of a day and submit its answer
+    Submit(SubmitArgs),
+    /// Extract the examples of the cached puzzle descriptions of the selected days into fixtures
+    Examples {
+        /// Days to select, e.g. `6`, `1-12`, `3,5,9` or `all`. Defaults to all of them.
+        #[arg(value_parser = parse_days)]
+        days: Vec<DaySelection>,
+
+        /// Event year of the selected days. Defaults to the latest year with solutions.
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_YEAR as i64..))]
+        year: Option<u16>,
+
+        /// Replace the existing fixtures
+        #[arg(long)]
+        force: bool,
+    },
+    /// List the available days
+    List,
+}
+
+#[derive(Args, Clone, Default)]
+pub struct RunArgs {
+    /// Days to select, e.g. `6`, `1-12`, `3,5,9` or `all`.
+    /// Defaults to the latest unlocked day with a solution.
+    #[arg(value_parser = parse_days)]
+    pub days: Vec<DaySelection>,
+
+    /// Event year of the selected days. Defaults to the latest year with solutions.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_YEAR as i64..))]
+    pub year: Option<u16>,
+
+    /// Only run one part of each day
+    #[arg(short, long, value_enum)]
+    pub part: Option<Part>,
+
+    /// Read the puzzle input from PATH instead of `input/YYYY/dayNN.txt`, or from stdin if PATH is `-`.
+    /// Requires exactly one selected day.
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Output format of the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Solve the selected days concurrently instead of one after another
+    #[arg(long)]
+    pub parallel: bool,
+
+    /// Give up on a day that takes longer than SECONDS and report it as timed out
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+}
+
+impl RunArgs {
+    /// The selected days in ascending order, without duplicates.
+    pub fn days(&self) -> Vec<u8> {
+        expand(&self.days)
+    }
+
+    /// The selected year, or the latest one with solutions.
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or_else(default_year)
+    }
+
+    /// Checks the combination of options that clap cannot validate on its own.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.input.is_some() && self.days().len() != 1 {
+            return Err("--input can only be used with exactly one day".to_owned());
+        }
+        Ok(())
+    }
+
+    pub fn runs(&self, part: Part) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// The selected parts of each day.
+    pub fn parts(&self) -> Vec<Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| self.runs(part))
+            .collect()
+    }
+}
+
+#[derive(Args, Clone)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub run: RunArgs,
+
+    /// Number of measured runs of each day
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// Number of unmeasured runs of each day before measuring
+    #[arg(short, long, default_value_t = 2)]
+    pub warmup: u32,
+
+    /// Flag parts whose median is more than PERCENT slower than the baseline run
+    #[arg(short, long, value_name = "PERCENT", default_value_t = 10.0)]
+    pub threshold: f64,
+
+    /// Recorded run to compare against
+    #[arg(short, long, value_enum, default_value_t = Baseline::Previous)]
+    pub baseline: Baseline,
+
+    /// Do not record this run in the benchmark history
+    #[arg(long)]
+    pub no_save: bool,
+}
+
+impl BenchArgs {
+    pub fn validate(&self) -> Result<(), String> {
+        self.run.validate()?;
+        // Every measured run reads the input again, which only works for files
+        if self.run.input.as_deref() == Some(Path::new("-")) {
+            return Err("bench cannot read the input from stdin, pass a file instead".to_owned());
+        }
+        // Concurrent days would skew each other's timings
+        if self.run.parallel || self.run.timeout.is_some() {
+            return Err(
+                "bench runs one day at a time, --parallel and --timeout are not supported"
+                    .to_owned(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Args, Clone)]
+pub struct FetchArgs {
+    /// Days to download, e.g. `6`, `1-12`, `3,5,9` or `all`.
+    /// Defaults to the latest unlocked day.
+    #[arg(value_parser = parse_days)]
+    pub days: Vec<DaySelection>,
+
+    /// Event year of the selected days. Defaults to the latest event that has started.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_YEAR as i64..))]
+    pub year: Option<u16>,
+
+    /// Also download the puzzle descriptions, replacing the cached ones
+    #[arg(long)]
+    pub puzzle: bool,
+
+    #[command(flatten)]
+    pub server: ServerArgs,
+}
+
+impl FetchArgs {
+    /// The selected days in ascending order, without duplicates.
+    pub fn days(&self) -> Vec<u8> {
+        expand(&self.days)
+    }
+}
+
+#[derive(Args, Clone)]
+pub struct SubmitArgs {
+    #[arg(value_parser = parse_day)]
+    pub day: u8,
+
+    #[arg(value_enum)]
+    pub part: Part,
+
+    /// Event year of the day. Defaults to the latest year with solutions.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(FIRST_YEAR as i64..))]
+    pub year: Option<u16>,
+
+    #[command(flatten)]
+    pub server: ServerArgs,
+}
+
+#[derive(Args, Clone)]
+pub struct ServerArgs {
+    /// Talk to a stand-in for the Advent of Code website
+    #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+}
+
+/// One command-line day argument, expanded to the days it covers.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DaySelection(Vec<u8>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        DaySelection((1..=LAST_DAY).collect())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Parts are stored as their number.
+#[cfg(feature = "serde")]
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Part {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(de::Error::custom(format!(
+                "expected part 1 or 2, found {}",
+                part
+            ))),
+        }
+    }
+}
+
+/// The days covered by some day arguments, or every day if there are none.
+pub fn days_or_all(selections: &[DaySelection]) -> Vec<u8> {
+    if selections.is_empty() {
+        DaySelection::all().0
+    } else {
+        expand(selections)
+    }
+}
+
+/// The latest year with solutions, used when no year is given.
+pub fn default_year() -> u16 {
+    years().last().copied().unwrap_or(FIRST_YEAR)
+}
+
+// The days covered by the day arguments, in ascending order and without duplicates
+fn expand(selections: &[DaySelection]) -> Vec<u8> {
+    let mut days: Vec<u8> = selections
+        .iter()
+        .flat_map(|s| s.0.iter().copied())
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+    days
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    let day: u8 = s
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{}` is not a day number", s.trim()))?;
+    if (1..=LAST_DAY).contains(&day) {
+        Ok(day)
+    } else {
+        Err(format!(
+            "day must be between 1 and {}, got {}",
+            LAST_DAY, day
+        ))
+    }
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|&secs| secs > 0.0)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| format!("`{}` is not a positive number of seconds", s))
+}
+
+// Accepts `all`, a single day, an inclusive range `a-b` or a comma-separated list of those
+fn parse_days(s: &str) -> Result<DaySelection, String> {
+    if s == "all" {
+        return Ok(DaySelection::all());
+    }
+
+    let mut days = Vec::new();
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("range {} is empty", item.trim()));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    Ok(DaySelection(days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        let days = |s| parse_days(s).map(|d| d.0);
+        assert_eq!(days("6"), Ok(vec![6]));
+        assert_eq!(days("1-4"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(days("3,5,9"), Ok(vec![3, 5, 9]));
+        assert_eq!(days("1-2,7,10-11"), Ok(vec![1, 2, 7, 10, 11]));
+        assert_eq!(days("all").map(|d| d.len()), Ok(25));
+    }
+
+    #[test]
+    fn test_parse_days_invalid() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("1,").is_err());
+    }
+
+    #[test]
+    fn test_cli_without_subcommand() {
+        let cli = Cli::try_parse_from(["aoc", "1-3", "5", "--part", "2"]).unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!(cli.run.days(), vec![1, 2, 3, 5]);
+        assert_eq!(cli.run.part, Some(Part::Two));
+        assert_eq!(cli.run.year(), default_year());
+    }
+
+    #[test]
+    fn test_input_needs_single_day() {
+        for args in [
+            vec!["aoc", "-i", "x.txt"],
+            vec!["aoc", "1,2", "-i", "x.txt"],
+        ] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert!(cli.run.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_cli_subcommands() {
+        let cli = Cli::try_parse_from(["aoc", "bench", "3,1,3", "-n", "5"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Bench(ref args)) if args.run.days() == vec![1, 3] && args.iterations == 5
+        ));
+        assert!(Cli::try_parse_from(["aoc", "bench", "-n", "0"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "bench", "9", "-i", "-"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Bench(ref args)) if args.validate().is_err()));
+
+        let cli = Cli::try_parse_from(["aoc", "run", "9", "--input", "-"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Run(ref args)) if args.validate().is_ok()));
+
+        let cli = Cli::try_parse_from(["aoc", "all", "--parallel", "--timeout", "1.5"]).unwrap();
+        assert!(cli.run.parallel);
+        assert_eq!(cli.run.timeout, Some(Duration::from_millis(1500)));
+        assert!(Cli::try_parse_from(["aoc", "--timeout", "0"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "bench", "--parallel"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Bench(ref args)) if args.validate().is_err()));
+
+        let cli = Cli::try_parse_from(["aoc", "check", "--year", "2023"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Check(ref args)) if args.year() == 2023));
+        assert!(Cli::try_parse_from(["aoc", "--year", "2014"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "fetch", "1-3", "-y", "2023"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Fetch(ref args)) if args.days() == vec![1, 2, 3] && args.year == Some(2023)
+        ));
+
+        let cli = Cli::try_parse_from(["aoc", "submit", "6", "2"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Submit(ref args)) if args.day == 6 && args.part == Part::Two
+        ));
+        assert!(Cli::try_parse_from(["aoc", "submit", "6"]).is_err());
+
+        assert!(Cli::try_parse_from(["aoc", "new", "26"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--part", "3"]).is_err());
+    }
+}
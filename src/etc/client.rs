@@ -0,0 +1,343 @@
+use crate::etc::cli::Part;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The Advent of Code website, used unless another base URL is given.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// File holding the session token if the environment variable is not set. It is not tracked.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+// Advent of Code asks automated tools to identify themselves
+const USER_AGENT: &str = concat!("aoc-jmm/", env!("CARGO_PKG_VERSION"));
+
+/// Why a request to the Advent of Code website failed.
+#[derive(Debug)]
+pub enum ClientError {
+    /// There is no session token in the environment or the session file.
+    MissingSession,
+    /// The server answered with an error status, and the first line of its message.
+    Status(u16, String),
+    /// The server could not be reached.
+    Transport(String),
+    /// The response could not be read or saved.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session token, set {} or write it to {}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            ClientError::Status(404, _) => {
+                write!(f, "the server answered 404, is the puzzle unlocked yet?")
+            }
+            // An expired or invalid session is answered with a 400 or a 500
+            ClientError::Status(status @ (400 | 500), message) => write!(
+                f,
+                "the server answered {} ({}), is the session token still valid?",
+                status, message
+            ),
+            ClientError::Status(status, message) => {
+                write!(f, "the server answered {} ({})", status, message)
+            }
+            ClientError::Transport(message) => write!(f, "request failed: {}", message),
+            ClientError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => {
+                let body = response.into_string().unwrap_or_default();
+                let message = body.lines().next().unwrap_or_default().trim().to_owned();
+                ClientError::Status(status, message)
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// The session token from the `AOC_SESSION` environment variable, or else from the session file.
+pub fn session_token() -> Result<String, ClientError> {
+    read_session(env::var(SESSION_VAR).ok(), Path::new(SESSION_FILE))
+}
+
+// The token is the value of the `session` cookie, with or without the `session=` prefix
+fn read_session(var: Option<String>, file: &Path) -> Result<String, ClientError> {
+    let text = match var {
+        Some(var) => var,
+        None => match read_to_string(file) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(ClientError::Io {
+                    path: file.to_path_buf(),
+                    source,
+                })
+            }
+        },
+    };
+
+    let token = text.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+    if token.is_empty() {
+        Err(ClientError::MissingSession)
+    } else {
+        Ok(token.to_owned())
+    }
+}
+
+/// A logged-in connection to the Advent of Code website, or to a stand-in at another base URL.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Submits the answer to a part, and returns the page of the reply.
+    pub fn answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let path = format!("/{}/day/{}/answer", year, day);
+        let response = self
+            .request("POST", &path)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        read_body(&path, response)
+    }
+
+    /// Downloads the description of a puzzle, which includes part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let path = format!("/{}/day/{}", year, day);
+        read_body(&path, self.request("GET", &path).call())
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let path = format!("/{}/day/{}/input", year, day);
+        read_body(&path, self.request("GET", &path).call())
+    }
+}
+
+fn read_body(
+    path: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    response?.into_string().map_err(|source| ClientError::Io {
+        path: PathBuf::from(path),
+        source,
+    })
+}
+
+/// Downloads the puzzle input of a day to `path`, unless that file already has an input.
+/// Inputs never change, so an input is never downloaded again. An empty file, like the
+/// placeholder created with a new day, is not an input.
+///
+/// Returns whether the input was downloaded.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<bool, ClientError> {
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(false);
+    }
+
+    save(path, &client.input(year, day)?)?;
+    Ok(true)
+}
+
+/// Downloads the description of a puzzle to `path`, replacing the one cached there if any.
+pub fn fetch_puzzle(client: &Client, year: u16, day: u8, path: &Path) -> Result<(), ClientError> {
+    save(path, &client.puzzle(year, day)?)
+}
+
+fn save(path: &Path, text: &str) -> Result<(), ClientError> {
+    let io_error = |source| ClientError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, text).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::input::input_path;
+    use crate::etc::scaffold::scaffold_day;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Serves each response to one request in turn on a local port, and sends back every request
+    /// received. Returns the base URL of the server.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body_in = vec![0; length];
+                reader.read_exact(&mut body_in).unwrap();
+                request.push_str(&String::from_utf8(body_in).unwrap());
+                sender.send(request).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    #[test]
+    fn test_read_session() {
+        let missing = Path::new("/nonexistent/session");
+        assert_eq!(
+            read_session(Some(" abc123\n".to_owned()), missing).unwrap(),
+            "abc123"
+        );
+        assert_eq!(
+            read_session(Some("session=abc123".to_owned()), missing).unwrap(),
+            "abc123"
+        );
+        assert!(matches!(
+            read_session(None, missing),
+            Err(ClientError::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, requests) = serve(vec![(200, "1 2\n3 4\n"), (404, "Not Found")]);
+        let client = Client::new(&(base_url + "/"), "abc123".to_owned());
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("2024/day01.txt");
+
+        assert!(fetch_input(&client, 2024, 1, &path).unwrap());
+        assert_eq!(read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+
+        // The file exists now, so the server is not asked again
+        assert!(!fetch_input(&client, 2024, 1, &path).unwrap());
+
+        let missing = dir.join("2024/day02.txt");
+        assert!(matches!(
+            fetch_input(&client, 2024, 2, &missing),
+            Err(ClientError::Status(404, _))
+        ));
+        assert!(!missing.exists());
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /2024/day/2/input "));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_after_scaffold() {
+        let (base_url, requests) = serve(vec![(200, "1 2\n")]);
+        let client = Client::new(&base_url, "abc123".to_owned());
+        let root = env::temp_dir().join(format!("aoc-fetch-new-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/main.rs"), "mod etc;\n\nfn main() {}\n").unwrap();
+
+        // The input file left empty by a new day is still downloaded into
+        scaffold_day(&root, 2025, 1, "").unwrap();
+        let path = root.join(input_path(2025, 1));
+        assert_eq!(read_to_string(&path).unwrap(), "");
+        assert!(fetch_input(&client, 2025, 1, &path).unwrap());
+        assert_eq!(read_to_string(&path).unwrap(), "1 2\n");
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /2025/day/1/input "));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_answer() {
+        let page = "<article><p>That's the right answer!</p></article>";
+        let (base_url, requests) = serve(vec![(200, page)]);
+        let client = Client::new(&base_url, "abc123".to_owned());
+
+        assert_eq!(client.answer(2024, 6, Part::Two, "4 2").unwrap(), page);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/6/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=4+2"));
+    }
+}
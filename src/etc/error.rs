@@ -0,0 +1,119 @@
+use crate::etc::cli::Part;
+use crate::Solution;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub type SolveResult = Result<Solution, SolveError>;
+
+/// Why a day could not be solved.
+#[derive(Debug)]
+pub enum SolveError {
+    /// The puzzle input could not be read.
+    MissingInput { path: PathBuf, source: io::Error },
+    /// The puzzle input does not have the expected shape. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// There is no solver for the requested day of the given year.
+    InvalidDay(u16, u8),
+    /// The solver ran longer than the allowed time.
+    TimedOut(Duration),
+    /// The solver panicked.
+    Panicked,
+    /// An answer drawn as block letters could not be read. Columns start at 1.
+    Ocr { column: usize, message: String },
+    /// A part answered although it is not in the `PARTS` of its solver.
+    Unlisted(Part),
+}
+
+impl SolveError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        SolveError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error located at `token`, which must be a slice of `text`.
+    pub fn at(text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = offset_of(text, token);
+        let before = &text[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        SolveError::parse(line, column, message)
+    }
+}
+
+fn offset_of(text: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    assert!(offset <= text.len(), "token is not a slice of the text");
+    offset
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::MissingInput { path, source } => {
+                write!(f, "could not read input {}: {}", path.display(), source)
+            }
+            SolveError::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            SolveError::InvalidDay(year, day) => {
+                write!(f, "there is no solver for day {} of {}", day, year)
+            }
+            SolveError::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+            SolveError::Panicked => write!(f, "the solver panicked"),
+            SolveError::Ocr { column, message } => {
+                write!(
+                    f,
+                    "could not read the letters at column {}: {}",
+                    column, message
+                )
+            }
+            SolveError::Unlisted(part) => {
+                write!(f, "part {} has an answer but is not listed in PARTS", part)
+            }
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::MissingInput { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let text = "12 34\n56 7x\n";
+        let token = &text[9..11];
+        assert!(matches!(
+            SolveError::at(text, token, "bad number"),
+            SolveError::Parse {
+                line: 2,
+                column: 4,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,321 @@
+use crate::etc::answers::{format_answer, Answers, Verdict};
+use crate::etc::cli::Part;
+use crate::etc::solver::DaySolver;
+use crate::{Solution, SolveError};
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Separates the expected answers of an example fixture from its input.
+const SEPARATOR: &str = "---";
+
+/// An example input from a puzzle description, with the expected answers it is given for.
+///
+/// Example fixtures are stored in `fixtures/YYYY/dayNN.txt`, and any further examples of the day
+/// in `dayNN-2.txt`, `dayNN-3.txt` and so on. The answers come first in the format of the answers
+/// files, then a `---` line, then the input exactly as given:
+///
+/// ```text
+/// 1: 11
+/// 2: 31
+/// ---
+/// 3   4
+/// 4   3
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub answers: Answers,
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(text: &str) -> Result<Example, String> {
+        let (header, input) = text
+            .strip_prefix("---\n")
+            .map(|input| ("", input))
+            .or_else(|| text.split_once("\n---\n"))
+            .ok_or_else(|| format!("missing a `{}` line before the input", SEPARATOR))?;
+
+        Ok(Example {
+            answers: Answers::parse(header)?,
+            input: input.to_owned(),
+        })
+    }
+}
+
+impl Display for Example {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for part in [Part::One, Part::Two] {
+            if let Some(answer) = self.answers.get(part) {
+                write!(f, "{}", format_answer(part, answer))?;
+            }
+        }
+        writeln!(f, "{}", SEPARATOR)?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Where the description of a puzzle is cached, relative to the repository root.
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("puzzles/{}/day{:02}.html", year, day))
+}
+
+/// The fixture file of the example at `index` (starting at 0) of a day, under `root`.
+pub fn example_path(root: &Path, year: u16, day: u8, index: usize) -> PathBuf {
+    let suffix = match index {
+        0 => String::new(),
+        index => format!("-{}", index + 1),
+    };
+    root.join(format!("fixtures/{}/day{:02}{}.txt", year, day, suffix))
+}
+
+/// The example fixture at `index` of a day in this repository, for the tests of its module.
+/// Panics if the fixture is missing or malformed.
+#[cfg(test)]
+pub fn fixture(year: u16, day: u8, index: usize) -> Example {
+    let path = example_path(Path::new(env!("CARGO_MANIFEST_DIR")), year, day, index);
+    let text = read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+    Example::parse(&text).unwrap_or_else(|msg| panic!("{}: {}", path.display(), msg))
+}
+
+/// The input and expected answer of the first example fixture of a day in this repository that
+/// has an answer to `part`. Panics if there is none, so that the test of a part fails until its
+/// example is recorded.
+#[cfg(test)]
+pub fn example_for(year: u16, day: u8, part: Part) -> (String, String) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    load_examples(root, year, day)
+        .unwrap()
+        .into_iter()
+        .find_map(|(_, example)| {
+            let answer = example.answers.get(part)?.to_owned();
+            Some((example.input, answer))
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "no fixture of day {} of {} has an answer to part {}",
+                day, year, part
+            )
+        })
+}
+
+/// Reads every example fixture of a day under `root`, in order.
+pub fn load_examples(root: &Path, year: u16, day: u8) -> io::Result<Vec<(PathBuf, Example)>> {
+    let mut examples = Vec::new();
+    for index in 0.. {
+        let path = example_path(root, year, day, index);
+        let text = match read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => break,
+            Err(err) => return Err(err),
+        };
+        let example = Example::parse(&text).map_err(|msg| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), msg),
+            )
+        })?;
+        examples.push((path, example));
+    }
+    Ok(examples)
+}
+
+/// Writes the fixtures of a day under `root`, keeping the existing ones unless `force`.
+/// Returns the files that were written.
+pub fn save_examples(
+    root: &Path,
+    year: u16,
+    day: u8,
+    examples: &[Example],
+    force: bool,
+) -> io::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for (index, example) in examples.iter().enumerate() {
+        let path = example_path(root, year, day, index);
+        if path.exists() && !force {
+            continue;
+        }
+        fs::create_dir_all(path.parent().expect("the file is in a directory"))?;
+        fs::write(&path, example.to_string())?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Solves the parts of a day that an example has an answer for, and compares them against it.
+/// Parts without a solution yet are left out.
+pub fn verify_example(
+    solver: &DaySolver,
+    example: &Example,
+) -> Result<Vec<(Part, Solution, Verdict)>, SolveError> {
+    let parts: Vec<Part> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| example.answers.get(part).is_some())
+        .collect();
+    let solved = solver.solve(&example.input, &parts)?;
+    Ok(solved
+        .parts
+        .into_iter()
+        .map(|result| {
+            let verdict = example.answers.verify(result.part, &result.solution);
+            (result.part, result.solution, verdict)
+        })
+        .collect())
+}
+
+/// Extracts the examples from the HTML description of a puzzle.
+///
+/// Each part is described in its own `<article>`. The first `<pre><code>` block of a part is
+/// taken as its example input, and the last emphasized code (`<code><em>`) as the expected
+/// answer. A part without a block of its own uses the example of the previous part, which is
+/// also the case when it repeats the same input. These are only conventions of the descriptions,
+/// so extracted fixtures are worth a review.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").expect("valid regex");
+    let answer = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>")
+        .expect("valid regex");
+
+    let mut examples: Vec<Example> = Vec::new();
+    let articles = html.split("<article").skip(1);
+    for (part, article) in [Part::One, Part::Two].into_iter().zip(articles) {
+        let article = article.split("</article>").next().unwrap_or_default();
+        let Some(expected) = answer
+            .captures_iter(article)
+            .last()
+            .and_then(|captures| captures.get(1).or(captures.get(2)))
+            .map(|text| unescape(text.as_str()))
+        else {
+            continue;
+        };
+
+        let input = block
+            .captures(article)
+            .map(|captures| unescape(&captures[1]));
+        let existing = match &input {
+            Some(input) => examples.iter().position(|example| &example.input == input),
+            None => examples.len().checked_sub(1),
+        };
+        let example = match (existing, input) {
+            (Some(index), _) => &mut examples[index],
+            (None, Some(input)) => {
+                examples.push(Example {
+                    answers: Answers::default(),
+                    input,
+                });
+                examples.last_mut().expect("just pushed")
+            }
+            (None, None) => continue,
+        };
+        example.answers.set(part, expected);
+    }
+    examples
+}
+
+// The text of some HTML, without its tags and with its entities decoded
+fn unescape(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::solver::solvers;
+
+    const PUZZLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>+mul(32,64]then(<em>mul(11,8)mul(8,5)</em>)</code></pre>
+<p>Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5 + 11*8 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>170807108</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>For example:</p>
+<pre><code>x<em>mul(2,4)</em>&amp;mul[3,7]!^<em>don't()</em>_mul(5,5)+mul(32,64](mul(11,8)un<em>do()</em>?<em>mul(8,5)</em>)</code></pre>
+<p>This time, the sum of the results is <code><em>48</em></code> (<code>2*4 + 8*5</code>).</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(PUZZLE);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(
+            examples[0].input,
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+        );
+        assert_eq!(examples[0].answers.get(Part::One), Some("161"));
+        assert_eq!(examples[0].answers.get(Part::Two), None);
+        assert_eq!(examples[1].answers.get(Part::Two), Some("48"));
+
+        // A part two reusing the example adds its answer to the same fixture
+        let same = PUZZLE.replace("x<em>mul(2,4)</em>&amp;mul[3,7]!^<em>don't()</em>_mul(5,5)+mul(32,64](mul(11,8)un<em>do()</em>?<em>mul(8,5)</em>)", "xmul(2,4)%&amp;mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+        let examples = extract_examples(&same);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answers.get(Part::Two), Some("48"));
+
+        // Before part one is solved, there is no second article
+        let examples = extract_examples(PUZZLE.split("<p>Your puzzle").next().unwrap());
+        assert_eq!(examples.len(), 1);
+    }
+
+    #[test]
+    fn test_example_round_trip() {
+        let example = Example::parse("1: 11\n2: 31\n---\n3   4\n4   3\n").unwrap();
+        assert_eq!(example.answers.get(Part::Two), Some("31"));
+        assert_eq!(example.input, "3   4\n4   3\n");
+        assert_eq!(example.to_string(), "1: 11\n2: 31\n---\n3   4\n4   3\n");
+
+        let example = Example::parse("---\nabc").unwrap();
+        assert_eq!(
+            example,
+            Example {
+                answers: Answers::default(),
+                input: "abc".to_owned()
+            }
+        );
+        assert!(Example::parse("1: 11\nabc").is_err());
+    }
+
+    #[test]
+    fn test_example_for() {
+        let (input, answer) = example_for(2024, 3, Part::Two);
+        assert_eq!(input, fixture(2024, 3, 1).input);
+        assert_eq!(answer, "48");
+    }
+
+    // Every registered day solves the example fixtures of its implemented parts
+    #[test]
+    fn test_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for solver in solvers() {
+            for (path, example) in load_examples(root, solver.year, solver.day).unwrap() {
+                for (part, _, verdict) in verify_example(solver, &example).unwrap() {
+                    assert_eq!(
+                        verdict,
+                        Verdict::Pass,
+                        "part {} of {}",
+                        part,
+                        path.display()
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use grid::Grid;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A position on a grid. Rows grow downwards and columns to the right, like the lines and
+/// characters of an input, so positions are never negative.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A displacement between two points, in rows and columns.
+///
+/// Like integers, offsets overflow with the `+`, `-` and `*` operators, which panic in debug
+/// builds and wrap in release builds. The `checked_*` methods return `None` instead, and grid
+/// positions are only moved with [`Point::checked_add`] and [`Point::step`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct Vec2 {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// One of the four directions on a grid.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Point { row, col }
+    }
+
+    /// The point moved by `offset`, or `None` if that would be at a negative position.
+    pub fn checked_add(self, offset: Vec2) -> Option<Point> {
+        Some(Point {
+            row: self.row.checked_add_signed(offset.row)?,
+            col: self.col.checked_add_signed(offset.col)?,
+        })
+    }
+
+    /// The offset from `other` to `self`, or `None` if it does not fit in a [`Vec2`].
+    pub fn checked_sub(self, other: Point) -> Option<Vec2> {
+        let offset = |a: usize, b: usize| {
+            let (a, b) = (isize::try_from(a).ok()?, isize::try_from(b).ok()?);
+            a.checked_sub(b)
+        };
+        Some(Vec2 {
+            row: offset(self.row, other.row)?,
+            col: offset(self.col, other.col)?,
+        })
+    }
+
+    /// The next point in `direction`, or `None` if that would be at a negative position.
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        self.checked_add(direction.offset())
+    }
+
+    /// The up to four orthogonal neighbors of the point that are within `grid`.
+    pub fn neighbors4<T>(self, grid: &Grid<T>) -> impl Iterator<Item = Point> {
+        self.neighbors(&Vec2::ORTHOGONAL, grid.size())
+    }
+
+    /// The up to eight orthogonal and diagonal neighbors of the point that are within `grid`.
+    pub fn neighbors8<T>(self, grid: &Grid<T>) -> impl Iterator<Item = Point> {
+        self.neighbors(&Vec2::ALL_AROUND, grid.size())
+    }
+
+    fn neighbors(
+        self,
+        offsets: &'static [Vec2],
+        (rows, cols): (usize, usize),
+    ) -> impl Iterator<Item = Point> {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.checked_add(offset))
+            .filter(move |point| point.row < rows && point.col < cols)
+    }
+
+    /// The distance between two points moving orthogonally.
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The distance between two points moving orthogonally or diagonally.
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point { row, col }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> Self {
+        (point.row, point.col)
+    }
+}
+
+/// The offset from `other` to `self`. Panics if it does not fit in a [`Vec2`], see
+/// [`Point::checked_sub`].
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        self.checked_sub(other)
+            .expect("the offset between two points fits in an isize")
+    }
+}
+
+impl Vec2 {
+    /// The offsets to the four orthogonal neighbors, clockwise from north.
+    pub const ORTHOGONAL: [Vec2; 4] = [
+        Vec2::new(-1, 0),
+        Vec2::new(0, 1),
+        Vec2::new(1, 0),
+        Vec2::new(0, -1),
+    ];
+
+    /// The offsets to the four diagonal neighbors, clockwise from north-east.
+    pub const DIAGONAL: [Vec2; 4] = [
+        Vec2::new(-1, 1),
+        Vec2::new(1, 1),
+        Vec2::new(1, -1),
+        Vec2::new(-1, -1),
+    ];
+
+    /// The offsets to all eight neighbors, clockwise from north.
+    pub const ALL_AROUND: [Vec2; 8] = [
+        Vec2::new(-1, 0),
+        Vec2::new(-1, 1),
+        Vec2::new(0, 1),
+        Vec2::new(1, 1),
+        Vec2::new(1, 0),
+        Vec2::new(1, -1),
+        Vec2::new(0, -1),
+        Vec2::new(-1, -1),
+    ];
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Vec2 { row, col }
+    }
+
+    pub fn checked_add(self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.row.checked_add(other.row)?,
+            self.col.checked_add(other.col)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.row.checked_sub(other.row)?,
+            self.col.checked_sub(other.col)?,
+        ))
+    }
+
+    pub fn checked_mul(self, factor: isize) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.row.checked_mul(factor)?,
+            self.col.checked_mul(factor)?,
+        ))
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> usize {
+        self.row.unsigned_abs().max(self.col.unsigned_abs())
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Vec2 {
+        Vec2::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::North => Vec2::new(-1, 0),
+            Direction::East => Vec2::new(0, 1),
+            Direction::South => Vec2::new(1, 0),
+            Direction::West => Vec2::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let point = Point::new(1, 0);
+        assert_eq!(point.checked_add(Vec2::new(-1, 2)), Some(Point::new(0, 2)));
+        assert_eq!(point.checked_add(Vec2::new(0, -1)), None);
+        assert_eq!(point.step(Direction::North), Some(Point::new(0, 0)));
+        assert_eq!(point.step(Direction::West), None);
+        assert_eq!(Point::new(0, 2) - point, Vec2::new(-1, 2));
+        assert_eq!(-Vec2::new(-1, 2) * 2 + Vec2::new(1, 1), Vec2::new(3, -3));
+
+        let far = Point::new(usize::MAX, 0);
+        assert_eq!(far.checked_sub(point), None);
+        assert_eq!(point.checked_sub(far), None);
+        let big = Vec2::new(isize::MAX, 1);
+        assert_eq!(big.checked_add(Vec2::new(1, 0)), None);
+        assert_eq!(big.checked_sub(Vec2::new(-1, 0)), None);
+        assert_eq!(big.checked_mul(2), None);
+        assert_eq!(Vec2::new(1, -2).checked_mul(-3), Some(Vec2::new(-3, 6)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: Grid<u8> = Grid::new(2, 3);
+        let corner: Vec<Point> = Point::new(0, 0).neighbors4(&grid).collect();
+        assert_eq!(corner, vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(Point::new(1, 1).neighbors4(&grid).count(), 3);
+        assert_eq!(Point::new(1, 1).neighbors8(&grid).count(), 5);
+        assert_eq!(Point::new(0, 1).neighbors8(&grid).count(), 5);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        for direction in Direction::ALL {
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 5), Point::new(4, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).manhattan(), 7);
+        assert_eq!((b - a).chebyshev(), 4);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::etc::geometry::Point;
+use crate::etc::parse::{self, parse_all};
+use crate::SolveError;
+use grid::Grid;
+use winnow::prelude::*;
+use winnow::token::none_of;
+
+/// Access to the cells of a grid by [`Point`].
+pub trait GridExt<T> {
+    fn at(&self, point: Point) -> Option<&T>;
+    fn at_mut(&mut self, point: Point) -> Option<&mut T>;
+    fn contains(&self, point: Point) -> bool;
+}
+
+impl<T> GridExt<T> for Grid<T> {
+    fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.row, point.col)
+    }
+
+    fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.row, point.col)
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        point.row < self.rows() && point.col < self.cols()
+    }
+}
+
+/// Parses a grid of characters, one row per line.
+///
+/// Lines may end with `\n` or `\r\n`, and blank lines at the end of the input are ignored.
+/// Every row must have as many cells as the first one.
+pub fn parse_grid(input: &str) -> Result<Grid<char>, SolveError> {
+    parse_grid_with(input, Some)
+}
+
+/// Parses a grid of single digits, such as a height map.
+pub fn parse_digits(input: &str) -> Result<Grid<u8>, SolveError> {
+    parse_grid_with(input, |c| c.to_digit(10).map(|digit| digit as u8))
+}
+
+/// Parses a grid like [`parse_grid`], turning each character into a cell with `cell`.
+/// A character that `cell` maps to `None` is reported as a parse error at its position.
+pub fn parse_grid_with<T>(
+    input: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, SolveError> {
+    parse_all(
+        input,
+        parse::grid(none_of(['\r', '\n']).verify_map(&mut cell)),
+    )
+}
+
+/// The position of the first cell equal to `value`, in row-major order.
+pub fn find<T: PartialEq>(grid: &Grid<T>, value: &T) -> Option<Point> {
+    find_all(grid, value).next()
+}
+
+/// The positions of every cell equal to `value`, in row-major order.
+pub fn find_all<'a, T: PartialEq>(
+    grid: &'a Grid<T>,
+    value: &'a T,
+) -> impl Iterator<Item = Point> + 'a {
+    grid.indexed_iter()
+        .filter(move |(_, cell)| *cell == value)
+        .map(|(position, _)| Point::from(position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = parse_grid("ab.\r\nc#d\r\n\r\n\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(
+            parse_grid("ab\ncd").unwrap(),
+            parse_grid("ab\ncd\n").unwrap()
+        );
+
+        assert!(matches!(
+            parse_grid("abc\nab\nabc"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_grid("ab\nabc"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(parse_grid("\n\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = parse_digits("012\n345\n").unwrap();
+        assert_eq!(grid[(1, 2)], 5);
+        assert!(matches!(
+            parse_digits("012\n3.5\n"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = parse_grid("..^\n^..").unwrap();
+        assert_eq!(find(&grid, &'^'), Some(Point::new(0, 2)));
+        assert_eq!(find(&grid, &'#'), None);
+        assert_eq!(
+            find_all(&grid, &'^').collect::<Vec<_>>(),
+            vec![Point::new(0, 2), Point::new(1, 0)]
+        );
+    }
+}
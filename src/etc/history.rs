@@ -0,0 +1,330 @@
+use crate::etc::bench::DayBench;
+use crate::etc::cli::Part;
+use crate::etc::input::LEGACY_YEAR;
+use crate::etc::output::{json_string, JsonRecord};
+use chrono::{DateTime, SecondsFormat, Utc};
+use clap::ValueEnum;
+use hashbrown::HashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+
+/// Where benchmark runs are recorded, relative to the repository root.
+pub const HISTORY_PATH: &str = "bench_history.csv";
+
+const HEADER: &str = "timestamp,commit,year,day,part,median_ms";
+
+/// The median runtime of one part of a day in one benchmark run.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub commit: Option<String>,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub median_ms: f64,
+}
+
+impl JsonRecord for HistoryEntry {
+    fn json_fields(&self) -> Vec<(&'static str, String)> {
+        let timestamp = self.timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        vec![
+            ("timestamp", json_string(&timestamp)),
+            (
+                "commit",
+                self.commit
+                    .as_deref()
+                    .map_or("null".to_owned(), json_string),
+            ),
+            ("year", self.year.to_string()),
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("median_ms", format!("{:?}", self.median_ms)),
+        ]
+    }
+}
+
+/// Which earlier run a benchmark is compared against.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Baseline {
+    /// The most recent recorded run
+    Previous,
+    /// The fastest recorded run
+    Best,
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Baseline::Previous => write!(f, "previous"),
+            Baseline::Best => write!(f, "best"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+}
+
+impl Regression {
+    pub fn slowdown_percent(&self) -> f64 {
+        (self.current_ms / self.baseline_ms - 1.0) * 100.0
+    }
+}
+
+/// Builds the history entries of a benchmark run that just finished.
+pub fn entries_for(results: &[DayBench], timestamp: DateTime<Utc>) -> Vec<HistoryEntry> {
+    let commit = git_commit();
+    results
+        .iter()
+        .flat_map(|result| {
+            result.parts.iter().map(|&(part, stats)| HistoryEntry {
+                timestamp,
+                commit: commit.clone(),
+                year: result.year,
+                day: result.day,
+                part,
+                median_ms: stats.median,
+            })
+        })
+        .collect()
+}
+
+/// Reads every recorded run, oldest first. A missing history file is an empty history.
+pub fn load_history(path: &Path) -> io::Result<Vec<HistoryEntry>> {
+    let text = match read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    text.lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            parse_entry(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed entry", path.display(), number + 1),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Appends entries to the history file, creating it with a header if needed.
+pub fn append_history(path: &Path, entries: &[HistoryEntry]) -> io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for entry in entries {
+        writeln!(file, "{}", format_entry(entry))?;
+    }
+    Ok(())
+}
+
+/// Renders entries as CSV, in the format of the history file.
+pub fn to_csv(entries: &[HistoryEntry]) -> String {
+    let mut out = format!("{}\n", HEADER);
+    for entry in entries {
+        out.push_str(&format_entry(entry));
+        out.push('\n');
+    }
+    out
+}
+
+fn format_entry(entry: &HistoryEntry) -> String {
+    format!(
+        "{},{},{},{},{},{}",
+        entry.timestamp.to_rfc3339_opts(SecondsFormat::Secs, true),
+        entry.commit.as_deref().unwrap_or(""),
+        entry.year,
+        entry.day,
+        entry.part,
+        entry.median_ms
+    )
+}
+
+// Entries recorded before multi-year support have no year column and belong to the legacy year
+fn parse_entry(line: &str) -> Option<HistoryEntry> {
+    let with_year = line.split(',').count() == 6;
+    let mut fields = line.split(',');
+    let entry = HistoryEntry {
+        timestamp: fields.next()?.parse().ok()?,
+        commit: Some(fields.next()?.to_owned()).filter(|c| !c.is_empty()),
+        year: match with_year {
+            true => fields.next()?.parse().ok()?,
+            false => LEGACY_YEAR,
+        },
+        day: fields.next()?.parse().ok()?,
+        part: Part::from_str(fields.next()?, false).ok()?,
+        median_ms: fields.next()?.parse().ok()?,
+    };
+    fields.next().is_none().then_some(entry)
+}
+
+/// The short hash of the checked out commit, if this is a git repository.
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Compares the current entries against the recorded history, flagging every part whose median
+/// is more than `threshold_percent` slower than the baseline. Parts without history are skipped.
+pub fn find_regressions(
+    history: &[HistoryEntry],
+    current: &[HistoryEntry],
+    baseline: Baseline,
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    let mut baselines: HashMap<(u16, u8, Part), f64> = HashMap::new();
+    for entry in history {
+        let key = (entry.year, entry.day, entry.part);
+        match baseline {
+            // The history is in chronological order, so the last entry wins
+            Baseline::Previous => {
+                baselines.insert(key, entry.median_ms);
+            }
+            Baseline::Best => {
+                let best = baselines.entry(key).or_insert(entry.median_ms);
+                *best = best.min(entry.median_ms);
+            }
+        }
+    }
+
+    current
+        .iter()
+        .filter_map(|entry| {
+            let baseline_ms = *baselines.get(&(entry.year, entry.day, entry.part))?;
+            (entry.median_ms > baseline_ms * (1.0 + threshold_percent / 100.0)).then_some(
+                Regression {
+                    year: entry.year,
+                    day: entry.day,
+                    part: entry.part,
+                    baseline_ms,
+                    current_ms: entry.median_ms,
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::output::to_json;
+
+    fn entry(day: u8, part: Part, median_ms: f64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: "2024-12-12T10:00:00Z".parse().unwrap(),
+            commit: Some("8db6602".to_owned()),
+            year: 2024,
+            day,
+            part,
+            median_ms,
+        }
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let line = "2024-12-12T10:00:00Z,8db6602,2024,6,2,301.5";
+        assert_eq!(parse_entry(line), Some(entry(6, Part::Two, 301.5)));
+
+        let line = "2024-12-12T10:00:00Z,,2023,6,1,3.5";
+        let parsed = parse_entry(line).unwrap();
+        assert_eq!((parsed.commit, parsed.year), (None, 2023));
+
+        // Legacy entry without a year
+        let line = "2024-12-12T10:00:00Z,8db6602,6,2,301.5";
+        assert_eq!(parse_entry(line), Some(entry(6, Part::Two, 301.5)));
+
+        assert_eq!(parse_entry("2024-12-12T10:00:00Z,,2024,6,3,3.5"), None);
+        assert_eq!(parse_entry("2024-12-12T10:00:00Z,,6,1"), None);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let entries = [entry(6, Part::Two, 301.5)];
+        let csv = to_csv(&entries);
+        assert_eq!(
+            csv,
+            "timestamp,commit,year,day,part,median_ms\n2024-12-12T10:00:00Z,8db6602,2024,6,2,301.5\n"
+        );
+        assert_eq!(
+            parse_entry(csv.lines().nth(1).unwrap()),
+            Some(entries[0].clone())
+        );
+    }
+
+    // The JSON output is written without serde, in the layout serde would use
+    #[test]
+    fn test_to_json() {
+        let mut entries = vec![entry(6, Part::Two, 301.5), entry(6, Part::Two, 2.0)];
+        entries[1].commit = None;
+        entries[1].timestamp += chrono::Duration::milliseconds(250);
+        let json = to_json(&entries);
+        assert!(json.starts_with("[\n  {\n    \"timestamp\": \"2024-12-12T10:00:00Z\",\n    \"commit\": \"8db6602\",\n    \"year\": 2024,"));
+        assert!(json.ends_with("\"timestamp\": \"2024-12-12T10:00:00.250Z\",\n    \"commit\": null,\n    \"year\": 2024,\n    \"day\": 6,\n    \"part\": 2,\n    \"median_ms\": 2.0\n  }\n]\n"));
+        #[cfg(feature = "serde")]
+        assert_eq!(json, serde_json::to_string_pretty(&entries).unwrap() + "\n");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let entries = vec![entry(6, Part::Two, 301.5)];
+        let json = serde_json::to_string(&entries).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"timestamp":"2024-12-12T10:00:00Z","commit":"8db6602","year":2024,"day":6,"part":2,"median_ms":301.5}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<HistoryEntry>>(&json).unwrap(),
+            entries
+        );
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let history = vec![
+            entry(6, Part::Two, 200.0),
+            entry(6, Part::Two, 300.0),
+            entry(1, Part::One, 1.0),
+        ];
+        let current = vec![
+            entry(6, Part::Two, 320.0),
+            entry(1, Part::One, 1.05),
+            entry(2, Part::One, 9.0),
+        ];
+
+        assert!(find_regressions(&history, &current, Baseline::Previous, 10.0).is_empty());
+
+        let regressions = find_regressions(&history, &current, Baseline::Best, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!((regressions[0].day, regressions[0].baseline_ms), (6, 200.0));
+        assert!((regressions[0].slowdown_percent() - 60.0).abs() < 1e-9);
+
+        let regressions = find_regressions(&history, &current, Baseline::Previous, 4.0);
+        assert_eq!(regressions.len(), 2);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::SolveError;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The only year whose files may still be in the layout from before multi-year support, directly
+/// in `input/` and `answers/` instead of a directory per year.
+pub const LEGACY_YEAR: u16 = 2024;
+
+/// Where the puzzle input of a day is stored by default.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{}/day{:02}.txt", year, day))
+}
+
+/// `path` if it exists, otherwise the legacy `legacy` path of a [`LEGACY_YEAR`] file if that one
+/// exists instead.
+pub fn with_legacy_fallback(path: PathBuf, year: u16, legacy: impl FnOnce() -> PathBuf) -> PathBuf {
+    if year == LEGACY_YEAR && !path.exists() {
+        let legacy = legacy();
+        if legacy.exists() {
+            return legacy;
+        }
+    }
+    path
+}
+
+/// Where the puzzle input of a day is read from by default under `root`, including the legacy
+/// layout.
+pub fn default_input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    with_legacy_fallback(root.join(input_path(year, day)), year, || {
+        root.join(format!("input/day{:02}.txt", day))
+    })
+}
+
+/// Reads the puzzle input of a day, either from `source` or from its default location.
+/// A `source` of `-` reads the input from stdin instead.
+pub fn load_input(year: u16, day: u8, source: Option<&Path>) -> Result<String, SolveError> {
+    let path = source.map_or_else(
+        || default_input_path(Path::new(""), year, day),
+        Path::to_path_buf,
+    );
+
+    let result = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        read_to_string(&path)
+    };
+
+    result.map_err(|source| SolveError::MissingInput { path, source })
+}
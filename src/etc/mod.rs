@@ -1,3 +1,26 @@
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod cli;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod history;
+pub mod input;
+// No day has an answer drawn in letters yet
+#[allow(dead_code)]
+pub mod ocr;
+pub mod output;
+// Not every parser has a day using it yet
+#[allow(dead_code)]
+pub mod parse;
+pub mod scaffold;
+pub mod schedule;
+// Not every search has a day using it yet
+#[allow(dead_code)]
+pub mod search;
 pub mod solution;
-
-pub use solution::Solution;
\ No newline at end of file
+pub mod solver;
+pub mod submit;
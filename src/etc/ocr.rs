@@ -0,0 +1,401 @@
+//! Reading answers drawn as block letters, in the two fonts of Advent of Code: letters 4 pixels
+//! wide and 6 high, and letters 6 pixels wide and 10 high.
+
+use crate::etc::geometry::Point;
+use crate::{Solution, SolveError, SolveResult};
+use grid::Grid;
+
+// The letters of the 6 pixels high font, each row on its own line
+const SMALL: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+// The letters of the 10 pixels high font
+const LARGE: [(char, &str); 15] = [
+    (
+        'A',
+        "\
+..##..
+.#..#.
+#....#
+#....#
+#....#
+######
+#....#
+#....#
+#....#
+#....#",
+    ),
+    (
+        'B',
+        "\
+#####.
+#....#
+#....#
+#....#
+#####.
+#....#
+#....#
+#....#
+#....#
+#####.",
+    ),
+    (
+        'C',
+        "\
+.####.
+#....#
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#....#
+.####.",
+    ),
+    (
+        'E',
+        "\
+######
+#.....
+#.....
+#.....
+#####.
+#.....
+#.....
+#.....
+#.....
+######",
+    ),
+    (
+        'F',
+        "\
+######
+#.....
+#.....
+#.....
+#####.
+#.....
+#.....
+#.....
+#.....
+#.....",
+    ),
+    (
+        'G',
+        "\
+.####.
+#....#
+#.....
+#.....
+#.....
+#..###
+#....#
+#....#
+#...##
+.###.#",
+    ),
+    (
+        'H',
+        "\
+#....#
+#....#
+#....#
+#....#
+######
+#....#
+#....#
+#....#
+#....#
+#....#",
+    ),
+    (
+        'J',
+        "\
+...###
+....#.
+....#.
+....#.
+....#.
+....#.
+....#.
+#...#.
+#...#.
+.###..",
+    ),
+    (
+        'K',
+        "\
+#....#
+#...#.
+#..#..
+#.#...
+##....
+##....
+#.#...
+#..#..
+#...#.
+#....#",
+    ),
+    (
+        'L',
+        "\
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+######",
+    ),
+    (
+        'N',
+        "\
+#....#
+##...#
+##...#
+#.#..#
+#.#..#
+#..#.#
+#..#.#
+#...##
+#...##
+#....#",
+    ),
+    (
+        'P',
+        "\
+#####.
+#....#
+#....#
+#....#
+#####.
+#.....
+#.....
+#.....
+#.....
+#.....",
+    ),
+    (
+        'R',
+        "\
+#####.
+#....#
+#....#
+#....#
+#####.
+#..#..
+#...#.
+#...#.
+#....#
+#....#",
+    ),
+    (
+        'X',
+        "\
+#....#
+#....#
+.#..#.
+.#..#.
+..##..
+..##..
+.#..#.
+.#..#.
+#....#
+#....#",
+    ),
+    (
+        'Z',
+        "\
+######
+.....#
+.....#
+....#.
+...#..
+..#...
+.#....
+#.....
+#.....
+######",
+    ),
+];
+
+/// Reads the letters drawn on a grid with `#` or `█`, every other character being blank.
+pub fn read_letters(grid: &Grid<char>) -> SolveResult {
+    let lit = grid.iter().map(|&c| c == '#' || c == '█').collect();
+    read_bitmap(&Grid::from_vec(lit, grid.cols()))
+}
+
+/// Reads the letters drawn by lighting up `points`.
+pub fn read_points(points: impl IntoIterator<Item = Point>) -> SolveResult {
+    let points: Vec<Point> = points.into_iter().collect();
+    let rows = points.iter().map(|point| point.row + 1).max().unwrap_or(0);
+    let cols = points.iter().map(|point| point.col + 1).max().unwrap_or(0);
+    let mut bitmap = Grid::new(rows, cols);
+    for point in points {
+        bitmap[(point.row, point.col)] = true;
+    }
+    read_bitmap(&bitmap)
+}
+
+// Letters are told apart by the blank columns between them, and matched exactly against the font
+// of their height once the blank rows and columns around them are left out
+fn read_bitmap(bitmap: &Grid<bool>) -> SolveResult {
+    let lit_rows: Vec<usize> = (0..bitmap.rows())
+        .filter(|&row| bitmap.iter_row(row).any(|&lit| lit))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(SolveError::Ocr {
+            column: 1,
+            message: "nothing is drawn".to_owned(),
+        });
+    };
+
+    let font: &[(char, &str)] = match bottom - top + 1 {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => {
+            return Err(SolveError::Ocr {
+                column: 1,
+                message: format!("the letters are {} pixels high, not 6 or 10", height),
+            })
+        }
+    };
+
+    let is_blank = |col: usize| (top..=bottom).all(|row| !bitmap[(row, col)]);
+    let mut letters = String::new();
+    let mut col = 0;
+    while col < bitmap.cols() {
+        if is_blank(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < bitmap.cols() && !is_blank(col) {
+            col += 1;
+        }
+
+        let glyph: Vec<String> = (top..=bottom)
+            .map(|row| {
+                (start..col)
+                    .map(|col| if bitmap[(row, col)] { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let letter = font
+            .iter()
+            .find(|(_, picture)| trim_columns(picture) == glyph)
+            .map(|&(letter, _)| letter)
+            .ok_or_else(|| SolveError::Ocr {
+                column: start + 1,
+                message: format!("unknown letter\n{}", glyph.join("\n")),
+            })?;
+        letters.push(letter);
+    }
+
+    Ok(Solution::Str(letters))
+}
+
+// The rows of a letter without its blank columns on either side
+fn trim_columns(picture: &str) -> Vec<String> {
+    let rows: Vec<&str> = picture.lines().collect();
+    let lit = |col: usize| rows.iter().any(|row| row.as_bytes()[col] == b'#');
+    let width = rows[0].len();
+    let start = (0..width).find(|&col| lit(col)).unwrap_or(0);
+    let end = (0..width).rfind(|&col| lit(col)).map_or(0, |col| col + 1);
+    rows.iter().map(|row| row[start..end].to_owned()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::grid::parse_grid;
+
+    // Draws letters of a font side by side, one blank column apart, with a blank border
+    fn draw(font: &[(char, &str)], text: &str) -> String {
+        let pictures: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| {
+                let (_, picture) = font.iter().find(|(letter, _)| *letter == c).unwrap();
+                picture.lines().collect()
+            })
+            .collect();
+        let height = pictures[0].len();
+        let rows: Vec<String> = (0..height)
+            .map(|row| {
+                let line: Vec<&str> = pictures.iter().map(|picture| picture[row]).collect();
+                format!(".{}..", line.join("."))
+            })
+            .collect();
+        let blank = ".".repeat(rows[0].len());
+        format!("{}\n{}\n{}", blank, rows.join("\n"), blank)
+    }
+
+    #[test]
+    fn test_small_font() {
+        let alphabet: String = SMALL.iter().map(|&(letter, _)| letter).collect();
+        let grid = parse_grid(&draw(&SMALL, &alphabet)).unwrap();
+        assert_eq!(
+            read_letters(&grid).unwrap(),
+            Solution::from(alphabet.as_str())
+        );
+
+        let art = "\
+█  █ ████ █    ███
+█  █ █    █    █  █
+████ ███  █    █  █
+█  █ █    █    ███
+█  █ █    █    █
+█  █ ████ ████ █";
+        let lines: Vec<&str> = art.lines().collect();
+        let points = lines.iter().enumerate().flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '█')
+                .map(move |(col, _)| Point::new(row, col))
+        });
+        assert_eq!(read_points(points).unwrap(), Solution::from("HELP"));
+    }
+
+    #[test]
+    fn test_large_font() {
+        let alphabet: String = LARGE.iter().map(|&(letter, _)| letter).collect();
+        let grid = parse_grid(&draw(&LARGE, &alphabet)).unwrap();
+        assert_eq!(
+            read_letters(&grid).unwrap(),
+            Solution::from(alphabet.as_str())
+        );
+    }
+
+    #[test]
+    fn test_unreadable() {
+        let grid = parse_grid(&draw(&SMALL, "AB").replacen("###.", "#.#.", 1)).unwrap();
+        assert!(matches!(
+            read_letters(&grid),
+            Err(SolveError::Ocr { column: 7, .. })
+        ));
+        assert!(read_letters(&parse_grid("#.\n#.").unwrap()).is_err());
+        assert!(read_points([]).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use crate::etc::cli::Part;
+use crate::Solution;
+use clap::ValueEnum;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// How the runner prints its results.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum Format {
+    /// Human-readable report
+    #[default]
+    Text,
+    /// A JSON array with one object per part
+    Json,
+    /// TOML with one `[[record]]` table per part
+    #[cfg(feature = "serde")]
+    Toml,
+    /// CSV with a header row and one row per part
+    Csv,
+}
+
+/// One solved part, as emitted by the machine-readable formats.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RunRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Solution,
+    /// The variant of the answer, such as `U64`, since it is stored as a string
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub kind: String,
+    /// Time spent parsing the input of the day, shared by its parts
+    pub parse_ms: f64,
+    pub elapsed_ms: f64,
+    /// Outcome of the answer verification, only present when checking answers
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub status: Option<String>,
+}
+
+// The records of a TOML document, which cannot be a bare array
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct Records<T> {
+    record: Vec<T>,
+}
+
+/// A record of the JSON output, as its fields in order with their values already in JSON.
+/// The output does not depend on the `serde` feature, and matches what serde would write.
+pub trait JsonRecord {
+    fn json_fields(&self) -> Vec<(&'static str, String)>;
+}
+
+impl JsonRecord for RunRecord {
+    fn json_fields(&self) -> Vec<(&'static str, String)> {
+        let answer = match self.answer {
+            Solution::Unsolved => "null".to_owned(),
+            ref answer => json_string(&answer.to_string()),
+        };
+        let mut fields = vec![
+            ("year", self.year.to_string()),
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("answer", answer),
+            ("type", json_string(&self.kind)),
+            ("parse_ms", format!("{:?}", self.parse_ms)),
+            ("elapsed_ms", format!("{:?}", self.elapsed_ms)),
+        ];
+        if let Some(status) = &self.status {
+            fields.push(("status", json_string(status)));
+        }
+        fields
+    }
+}
+
+/// Renders records as a pretty-printed JSON array.
+pub fn to_json<T: JsonRecord>(records: &[T]) -> String {
+    if records.is_empty() {
+        return "[]\n".to_owned();
+    }
+    let objects: Vec<String> = records
+        .iter()
+        .map(|record| {
+            let fields: Vec<String> = record
+                .json_fields()
+                .into_iter()
+                .map(|(name, value)| format!("\"{}\": {}", name, value))
+                .collect();
+            format!("  {{\n    {}\n  }}", fields.join(",\n    "))
+        })
+        .collect();
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+/// Renders records as TOML, one `[[record]]` table each.
+#[cfg(feature = "serde")]
+pub fn to_toml<T: Serialize + Clone>(records: &[T]) -> String {
+    let record = records.to_vec();
+    toml::to_string(&Records { record }).expect("records are serializable")
+}
+
+/// Renders records as CSV. The status column is only present if some record has a status.
+pub fn to_csv(records: &[RunRecord]) -> String {
+    let with_status = records.iter().any(|r| r.status.is_some());
+
+    let mut out = String::from("year,day,part,answer,type,parse_ms,elapsed_ms");
+    out.push_str(if with_status { ",status\n" } else { "\n" });
+    for record in records {
+        write!(
+            out,
+            "{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            csv_field(&record.answer.to_string()),
+            record.kind,
+            record.parse_ms,
+            record.elapsed_ms
+        )
+        .unwrap();
+        if with_status {
+            write!(
+                out,
+                ",{}",
+                csv_field(record.status.as_deref().unwrap_or(""))
+            )
+            .unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A line of the text report, such as `  · Part 1: 42 (0.1234 ms)`. A multi-line answer goes
+/// below the line, indented, so that it keeps its shape.
+pub fn text_line(label: &str, solution: &Solution, detail: &str) -> String {
+    let answer = solution.to_string();
+    if !answer.contains('\n') {
+        return format!("  · {}: {} {}", label, answer, detail);
+    }
+    let mut out = format!("  · {}: {}", label, detail);
+    for line in answer.split('\n') {
+        write!(out, "\n      {}", line).unwrap();
+    }
+    out
+}
+
+/// A string as a JSON string literal.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(solutions: &[Solution], status: Option<&str>) -> Vec<RunRecord> {
+        solutions
+            .iter()
+            .zip([Part::One, Part::Two])
+            .map(|(solution, part)| RunRecord {
+                year: 2024,
+                day: 3,
+                part,
+                answer: solution.clone(),
+                kind: solution.kind().to_owned(),
+                parse_ms: 0.25,
+                elapsed_ms: 0.5,
+                status: status.map(str::to_owned),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_text_line() {
+        assert_eq!(
+            text_line("Part 1", &Solution::from(42u8), "(0.5 ms)"),
+            "  · Part 1: 42 (0.5 ms)"
+        );
+        assert_eq!(
+            text_line("Part 2", &"#..#\n.##.".parse().unwrap(), "[pass]"),
+            "  · Part 2: [pass]\n      #..#\n      .##."
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let records = records(&[Solution::from(161u32), Solution::from("a\"b")], None);
+        let json = to_json(&records);
+        assert!(json.starts_with("[\n  {\n    \"year\": 2024,\n    \"day\": 3,\n    \"part\": 1,"));
+        assert!(json.contains("\"answer\": \"a\\\"b\",\n    \"type\": \"Str\","));
+        assert!(json.contains("\"parse_ms\": 0.25,\n    \"elapsed_ms\": 0.5\n  }"));
+        assert!(!json.contains("status"));
+        #[cfg(feature = "serde")]
+        {
+            assert_eq!(
+                serde_json::from_str::<Vec<RunRecord>>(&json).unwrap(),
+                records
+            );
+            let checked = self::records(&[Solution::Unsolved, Solution::from(-1i64)], Some("fail"));
+            assert_eq!(
+                to_json(&checked),
+                serde_json::to_string_pretty(&checked).unwrap() + "\n"
+            );
+        }
+        assert_eq!(to_json(&records[..0]), "[]\n");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_toml() {
+        let records = records(&[Solution::from(161u32), Solution::Unsolved], Some("pass"));
+        let toml = to_toml(&records);
+        assert!(toml.starts_with("[[record]]\nyear = 2024\nday = 3\npart = 1\nanswer = \"161\"\n"));
+        let parsed: Records<RunRecord> = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed.record, records);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let solutions = [Solution::from(161u32), Solution::from("x,y")];
+        assert_eq!(
+            to_csv(&records(&solutions, Some("pass"))),
+            "year,day,part,answer,type,parse_ms,elapsed_ms,status\n2024,3,1,161,U32,0.25,0.5,pass\n2024,3,2,\"x,y\",Str,0.25,0.5,pass\n"
+        );
+        assert_eq!(
+            to_csv(&[]),
+            "year,day,part,answer,type,parse_ms,elapsed_ms\n"
+        );
+    }
+}
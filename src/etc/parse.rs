@@ -0,0 +1,242 @@
+//! Winnow parsers for the shapes puzzle inputs keep coming in: numbers, lists, `key: values`
+//! lines, sections separated by a blank line and grids of characters. They combine with each
+//! other and with any winnow parser, and [`parse_all`] runs them over a whole input, reporting
+//! where it stopped making sense.
+
+use crate::SolveError;
+use grid::Grid;
+use winnow::ascii::{dec_int, dec_uint, line_ending, multispace0, space0, space1, Int, Uint};
+use winnow::combinator::{
+    alt, cut_err, delimited, eof, not, peek, preceded, repeat, separated, separated_pair,
+    terminated,
+};
+use winnow::error::{ContextError, StrContext, StrContextValue};
+use winnow::prelude::*;
+use winnow::token::none_of;
+
+/// Parses all of `input` with `parser`, allowing blank lines at the end.
+///
+/// When the input does not match, the error is located at the line and column where parsing
+/// stopped, with what was expected there when the parser says so.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, ContextError>,
+) -> Result<O, SolveError> {
+    terminated(parser, multispace0)
+        .parse(input)
+        .map_err(|error| {
+            let rest = &input[error.offset()..];
+            let found = rest.chars().next().map_or_else(
+                || "the end of the input".to_owned(),
+                |c| format!("`{}`", c.escape_default()),
+            );
+            let expected = error.inner().to_string();
+            let message = if expected.is_empty() {
+                format!("unexpected {}", found)
+            } else {
+                format!("{}, found {}", expected.replace('\n', ", "), found)
+            };
+            SolveError::at(input, rest, message)
+        })
+}
+
+fn expected(description: &'static str) -> StrContext {
+    StrContext::Expected(StrContextValue::Description(description))
+}
+
+/// An unsigned integer in decimal.
+pub fn unsigned<T: Uint>(input: &mut &str) -> ModalResult<T> {
+    dec_uint
+        .context(expected("an unsigned integer"))
+        .parse_next(input)
+}
+
+/// An integer in decimal, with an optional `+` or `-` sign.
+pub fn signed<T: Int>(input: &mut &str) -> ModalResult<T> {
+    dec_int.context(expected("an integer")).parse_next(input)
+}
+
+/// One or more `item`s separated by spaces or tabs, which may also surround the list.
+pub fn spaced<'a, O>(
+    item: impl Parser<&'a str, O, ContextError>,
+) -> impl Parser<&'a str, Vec<O>, ContextError> {
+    delimited(space0, separated(1.., item, space1), space0)
+}
+
+/// One or more `item`s separated by commas. An item must follow every comma.
+pub fn comma_separated<'a, O>(
+    mut item: impl Parser<&'a str, O, ContextError>,
+) -> impl Parser<&'a str, Vec<O>, ContextError> {
+    move |input: &mut &'a str| {
+        let first = item.parse_next(input)?;
+        let rest: Vec<O> = repeat(0.., preceded(',', cut_err(item.by_ref()))).parse_next(input)?;
+        Ok(std::iter::once(first).chain(rest).collect())
+    }
+}
+
+/// One or more lines each matching `item`. The line ending after the last one is left to the
+/// caller, so that a blank line can end a section. A line that does not match is an error
+/// where it stops matching.
+pub fn lines<'a, O>(
+    mut item: impl Parser<&'a str, O, ContextError>,
+) -> impl Parser<&'a str, Vec<O>, ContextError> {
+    move |input: &mut &'a str| {
+        let first = item.parse_next(input)?;
+        let rest: Vec<O> =
+            repeat(0.., preceded(line_break, cut_err(item.by_ref()))).parse_next(input)?;
+        Ok(std::iter::once(first).chain(rest).collect())
+    }
+}
+
+/// A `key: values` line, where the values are separated by spaces.
+pub fn key_values<'a, K, V>(
+    key: impl Parser<&'a str, K, ContextError>,
+    value: impl Parser<&'a str, V, ContextError>,
+) -> impl Parser<&'a str, (K, Vec<V>), ContextError> {
+    separated_pair(key, (space0, ':'), spaced(value))
+}
+
+/// The blank line ending a section, with the line ending before it.
+pub fn blank_line(input: &mut &str) -> ModalResult<()> {
+    (line_ending, line_ending)
+        .void()
+        .context(expected("a blank line"))
+        .parse_next(input)
+}
+
+/// One or more sections matching `section`, separated by a blank line. Sections of different
+/// shapes are read with [`blank_line`] between them instead.
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, ContextError>,
+) -> impl Parser<&'a str, Vec<O>, ContextError> {
+    separated(1.., section, blank_line)
+}
+
+// A line ending followed by another line, rather than by a blank line or the end
+fn line_break<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    terminated(line_ending, not(alt((line_ending, eof)))).parse_next(input)
+}
+
+/// A grid of characters, one row per line, as wide as its first row.
+pub fn char_grid(input: &mut &str) -> ModalResult<Grid<char>> {
+    grid(none_of(['\r', '\n'])).parse_next(input)
+}
+
+/// A grid with a cell parsed by `cell` for each character, one row per line. A row that is
+/// shorter or longer than the first one is an error at the column where it differs.
+pub fn grid<'a, T>(
+    mut cell: impl Parser<&'a str, T, ContextError>,
+) -> impl Parser<&'a str, Grid<T>, ContextError> {
+    move |input: &mut &'a str| {
+        let mut cells: Vec<T> = repeat(1.., cell.by_ref())
+            .context(expected("a grid cell"))
+            .parse_next(input)?;
+        let cols = cells.len();
+
+        let row = cut_err(terminated(
+            repeat(cols, cell.by_ref()),
+            peek(alt((line_ending, eof))),
+        ))
+        .context(expected("a row as wide as the first one"));
+        let rows: Vec<Vec<T>> = repeat(0.., preceded(line_break, row)).parse_next(input)?;
+        cells.extend(rows.into_iter().flatten());
+
+        Ok(Grid::from_vec(cells, cols))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_all("42\n", unsigned::<u32>).unwrap(), 42);
+        assert_eq!(parse_all("-7", signed::<i64>).unwrap(), -7);
+        assert!(parse_all("-7", unsigned::<u32>).is_err());
+        assert!(parse_all("256", unsigned::<u8>).is_err());
+        assert_eq!(
+            parse_all(" 1 -2\t3 ", spaced(signed::<i32>)).unwrap(),
+            vec![1, -2, 3]
+        );
+        assert_eq!(
+            parse_all("1,2,3", comma_separated(unsigned::<u8>)).unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        let equations = parse_all(
+            "190: 10 19\r\n83: 17 5\n",
+            lines(key_values(unsigned::<u64>, unsigned::<u64>)),
+        );
+        assert_eq!(
+            equations.unwrap(),
+            vec![(190, vec![10, 19]), (83, vec![17, 5])]
+        );
+
+        let input = "1|2\n3|4\n\n1,2\n3\n\n";
+        let rule = separated_pair(unsigned::<u8>, '|', unsigned::<u8>);
+        let (rules, updates) = parse_all(
+            input,
+            separated_pair(
+                lines(rule),
+                blank_line,
+                lines(comma_separated(unsigned::<u8>)),
+            ),
+        )
+        .unwrap();
+        assert_eq!(rules, vec![(1, 2), (3, 4)]);
+        assert_eq!(updates, vec![vec![1, 2], vec![3]]);
+
+        let groups = parse_all("1\n2\n\n3\n\n4", sections(lines(unsigned::<u8>))).unwrap();
+        assert_eq!(groups, vec![vec![1, 2], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn test_grid() {
+        let chars = parse_all("ab.\r\nc#d\r\n\r\n", char_grid).unwrap();
+        assert_eq!((chars.rows(), chars.cols()), (2, 3));
+        assert_eq!(chars[(1, 1)], '#');
+
+        let digit = none_of(['\r', '\n']).verify_map(|c: char| c.to_digit(10));
+        let grids = parse_all("12\n34\n\n5\n", sections(grid(digit))).unwrap();
+        assert_eq!(grids[0][(1, 0)], 3);
+        assert_eq!(grids[1].size(), (1, 1));
+    }
+
+    #[test]
+    fn test_error_positions() {
+        fn position<T>(result: Result<T, SolveError>) -> (usize, usize) {
+            match result {
+                Err(SolveError::Parse { line, column, .. }) => (line, column),
+                _ => panic!("expected a parse error"),
+            }
+        }
+
+        assert_eq!(
+            position(parse_all("1 2\n3 x", lines(spaced(unsigned::<u32>)))),
+            (2, 3)
+        );
+        assert_eq!(
+            position(parse_all(
+                "12: ",
+                key_values(unsigned::<u32>, unsigned::<u32>)
+            )),
+            (1, 5)
+        );
+        let rule = separated_pair(unsigned::<u32>, '|', unsigned::<u32>);
+        assert_eq!(position(parse_all("1|2\n3|x\n5|6", lines(rule))), (2, 3));
+        assert_eq!(position(parse_all("abc\nab\nabc", char_grid)), (2, 3));
+        assert_eq!(position(parse_all("ab\nabc", char_grid)), (2, 3));
+        assert_eq!(position(parse_all("\n\n", char_grid)), (1, 1));
+
+        match parse_all("1,x", comma_separated(unsigned::<u32>)) {
+            Err(SolveError::Parse { message, .. }) => {
+                assert_eq!(message, "expected an unsigned integer, found `x`")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
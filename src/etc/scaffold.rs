@@ -0,0 +1,310 @@
+use crate::etc::answers::answers_path;
+use crate::etc::input::default_input_path;
+use std::fs::{self, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r##"use crate::etc::cli::Part;
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day{DD};
+
+impl Solver for Day{DD} {
+    const YEAR: u16 = {YEAR};
+    const DAY: u8 = {DAY};
+    const NAME: &'static str = "{NAME}";
+    // List the parts here as they get a solution
+    const PARTS: &'static [Part] = &[];
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> SolveResult {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> SolveResult {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day{DD}>() }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::example_for;
+
+    // These fail until the example of the part is in fixtures/{YEAR}/day{DD}.txt and solved
+
+    #[test]
+    fn test_part1() {
+        let (input, answer) = example_for({YEAR}, {DAY}, Part::One);
+        let input = Day{DD}::parse(&input).unwrap();
+        assert_eq!(Day{DD}::part1(&input).unwrap().to_string(), answer);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (input, answer) = example_for({YEAR}, {DAY}, Part::Two);
+        let input = Day{DD}::parse(&input).unwrap();
+        assert_eq!(Day{DD}::part2(&input).unwrap().to_string(), answer);
+    }
+}
+"##;
+
+// The stub that days without a solution were first written as, before the template had tests
+const BARE_STUB: &str = r##"use crate::etc::cli::Part;
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day{DD};
+
+impl Solver for Day{DD} {
+    const YEAR: u16 = {YEAR};
+    const DAY: u8 = {DAY};
+    const NAME: &'static str = "{NAME}";
+    // List the parts here as they get a solution
+    const PARTS: &'static [Part] = &[];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> SolveResult {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> SolveResult {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day{DD}>() }
+"##;
+
+const ANSWERS_PLACEHOLDER: &str = "# Record the accepted answers here, e.g. `1: 1234`\n";
+
+/// The source of a new day module.
+pub fn render(year: u16, day: u8, name: &str) -> String {
+    fill(TEMPLATE, year, day, name)
+}
+
+fn fill(template: &str, year: u16, day: u8, name: &str) -> String {
+    template
+        .replace("{DD}", &format!("{:02}", day))
+        .replace("{YEAR}", &year.to_string())
+        .replace("{DAY}", &day.to_string())
+        .replace("{NAME}", &name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Whether `source` is a module for the day as generated, whatever its title, so that nothing
+/// written since would be lost by replacing it.
+pub fn is_stub(source: &str, year: u16, day: u8) -> bool {
+    let untitled = |source: &str| -> Vec<String> {
+        source
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("const NAME:"))
+            .map(|line| line.trim_end().to_owned())
+            .collect()
+    };
+    let source = untitled(source);
+    [TEMPLATE, BARE_STUB]
+        .iter()
+        .any(|template| untitled(&fill(template, year, day, "")) == source)
+}
+
+/// Generates the module of a day under `root`, registers it in its year module (creating the
+/// year if needed), and creates empty input and answers files unless they already exist.
+///
+/// An existing module is only replaced if it is still a stub, see [`is_stub`].
+/// Returns the files that were created or changed.
+pub fn scaffold_day(root: &Path, year: u16, day: u8, name: &str) -> io::Result<Vec<PathBuf>> {
+    let main = root.join("src/main.rs");
+    if !main.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} not found, run from the repository root", main.display()),
+        ));
+    }
+
+    let year_dir = root.join(format!("src/y{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    if module.exists() && !is_stub(&read_to_string(&module)?, year, day) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} has changes that replacing it would lose",
+                module.display()
+            ),
+        ));
+    }
+
+    let mut changed = Vec::new();
+
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&module, render(year, day, name))?;
+    changed.push(module);
+
+    let year_mod = year_dir.join("mod.rs");
+    if !year_mod.exists() {
+        fs::write(&year_mod, "")?;
+    }
+    if add_declaration(&year_mod, &format!("pub mod day{:02};", day), "pub mod ")? {
+        changed.push(year_mod);
+    }
+    if add_declaration(&main, &format!("mod y{};", year), "mod ")? {
+        changed.push(main);
+    }
+
+    // Nothing is written where a file would already be read from, even in the legacy layout
+    let placeholders = [
+        (default_input_path(root, year, day), ""),
+        (answers_path(root, year, day), ANSWERS_PLACEHOLDER),
+    ];
+    for (path, contents) in placeholders {
+        if !path.exists() {
+            fs::create_dir_all(path.parent().expect("the file is in a directory"))?;
+            fs::write(&path, contents)?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+// Adds a line declaring a module next to the lines starting with `similar`, keeping them sorted.
+// Returns whether the declaration was missing.
+fn add_declaration(path: &Path, declaration: &str, similar: &str) -> io::Result<bool> {
+    let text = read_to_string(path)?;
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&declaration) {
+        return Ok(false);
+    }
+
+    let position = lines
+        .iter()
+        .rposition(|line| line.starts_with(similar) && *line < declaration)
+        .map(|i| i + 1)
+        .or_else(|| lines.iter().position(|line| line.starts_with(similar)))
+        .unwrap_or(lines.len());
+    lines.insert(position, declaration);
+
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A scratch copy of the parts of the repository that scaffolding touches
+    fn scratch_repo(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/y2024")).unwrap();
+        fs::write(
+            root.join("src/main.rs"),
+            "mod etc;\nmod y2024;\n\nfn main() {}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/y2024/mod.rs"),
+            "pub mod day01;\npub mod day03;\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_render() {
+        let source = render(2024, 7, "Bridge \"Repair\"");
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("const YEAR: u16 = 2024;"));
+        assert!(source.contains("const DAY: u8 = 7;"));
+        assert!(source.contains(r#"const NAME: &'static str = "Bridge \"Repair\"";"#));
+        assert!(!source.contains("{DD}"));
+    }
+
+    #[test]
+    fn test_is_stub() {
+        assert!(is_stub(&render(2024, 7, "Bridge Repair"), 2024, 7));
+        assert!(is_stub(
+            &fill(BARE_STUB, 2024, 13, "Claw Contraption"),
+            2024,
+            13
+        ));
+        assert!(!is_stub(&render(2024, 7, ""), 2024, 8));
+        let started = render(2024, 7, "").replace("&[]", "&[Part::One]");
+        assert!(!is_stub(&started, 2024, 7));
+    }
+
+    #[test]
+    fn test_scaffold_day() {
+        let root = scratch_repo("day");
+        let changed = scaffold_day(&root, 2024, 2, "Red-Nosed Reports").unwrap();
+        assert_eq!(changed.len(), 4);
+        assert_eq!(
+            read_to_string(root.join("src/y2024/mod.rs")).unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n"
+        );
+        assert_eq!(
+            read_to_string(root.join("input/2024/day02.txt")).unwrap(),
+            ""
+        );
+
+        // The module exists now, and is only replaced while it is still a stub
+        let changed = scaffold_day(&root, 2024, 2, "Reports").unwrap();
+        assert_eq!(changed, vec![root.join("src/y2024/day02.rs")]);
+        let module = root.join("src/y2024/day02.rs");
+        let source = read_to_string(&module).unwrap();
+        fs::write(
+            &module,
+            source.replace("// Your solution here...", "todo!()"),
+        )
+        .unwrap();
+        assert!(scaffold_day(&root, 2024, 2, "").is_err());
+
+        // Files in the legacy layout are still read, so no placeholder is added next to them
+        fs::write(root.join("input/day04.txt"), "MMMS\n").unwrap();
+        fs::create_dir_all(root.join("answers")).unwrap();
+        fs::write(root.join("answers/day04.txt"), "1: 18\n").unwrap();
+        let changed = scaffold_day(&root, 2024, 4, "Ceres Search").unwrap();
+        assert_eq!(changed.len(), 2);
+        assert!(!root.join("input/2024/day04.txt").exists());
+        assert!(!root.join("answers/2024/day04.txt").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_new_year() {
+        let root = scratch_repo("year");
+        scaffold_day(&root, 2025, 1, "").unwrap();
+        assert_eq!(
+            read_to_string(root.join("src/main.rs")).unwrap(),
+            "mod etc;\nmod y2024;\nmod y2025;\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            read_to_string(root.join("src/y2025/mod.rs")).unwrap(),
+            "pub mod day01;\n"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,183 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How a task run by [`run_tasks`] ended.
+#[derive(Debug, PartialEq)]
+pub enum Outcome<R> {
+    Done(R),
+    /// The task ran longer than the timeout. It is left running in the background.
+    TimedOut,
+    Panicked,
+}
+
+enum Event<R> {
+    Started(usize, Instant),
+    Finished(usize, Option<R>, Duration),
+}
+
+/// Runs `task` on every item and reports each outcome in the order of the items.
+///
+/// With `parallel`, the tasks run concurrently on the rayon pool, otherwise one after another.
+/// With a `timeout`, a task that runs longer than it is reported as timed out as soon as the
+/// timeout expires, and the remaining tasks go on. Threads cannot be cancelled, so it keeps its
+/// thread busy until it finishes or the process exits; tasks still queued behind it on the pool
+/// are then moved to threads of their own.
+pub fn run_tasks<T, R, F>(
+    items: &[T],
+    parallel: bool,
+    timeout: Option<Duration>,
+    task: F,
+    mut report: impl FnMut(&T, Outcome<R>),
+) where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(T) -> R + Send + Sync + 'static,
+{
+    // Nothing can be abandoned, so there is no need for other threads
+    if !parallel && timeout.is_none() {
+        for item in items {
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(|| task(item.clone()))) {
+                Ok(result) => Outcome::Done(result),
+                Err(_) => Outcome::Panicked,
+            };
+            report(item, outcome);
+        }
+        return;
+    }
+
+    let task = Arc::new(task);
+    let claimed: Arc<Vec<AtomicBool>> =
+        Arc::new(items.iter().map(|_| AtomicBool::new(false)).collect());
+    let (sender, receiver) = mpsc::channel();
+    let job = |index: usize| {
+        let (task, sender, item) = (Arc::clone(&task), sender.clone(), items[index].clone());
+        let claimed = Arc::clone(&claimed);
+        move || {
+            // A task moved off the pool is queued twice, whichever copy runs first does the work
+            if claimed[index].swap(true, Ordering::SeqCst) {
+                return;
+            }
+            let start = Instant::now();
+            // Sending fails once every task has been reported, which is fine for abandoned ones
+            let _ = sender.send(Event::Started(index, start));
+            let result = panic::catch_unwind(AssertUnwindSafe(|| task(item))).ok();
+            let _ = sender.send(Event::Finished(index, result, start.elapsed()));
+        }
+    };
+
+    if parallel {
+        (0..items.len()).for_each(|index| rayon::spawn(job(index)));
+    }
+
+    let mut started: Vec<Option<Instant>> = vec![None; items.len()];
+    let mut finished: Vec<Option<Outcome<R>>> = items.iter().map(|_| None).collect();
+
+    for next in 0..items.len() {
+        if !parallel {
+            thread::spawn(job(next));
+        }
+
+        let outcome = loop {
+            if let Some(outcome) = finished[next].take() {
+                break outcome;
+            }
+
+            // Only wait for the task being reported, the others are checked when their turn comes
+            let event = match (started[next], timeout) {
+                (Some(start), Some(timeout)) => {
+                    let remaining = (start + timeout).saturating_duration_since(Instant::now());
+                    match receiver.recv_timeout(remaining) {
+                        Ok(event) => event,
+                        Err(RecvTimeoutError::Timeout) => {
+                            // The abandoned task may hold the only pool thread
+                            if parallel {
+                                for index in 0..items.len() {
+                                    if !claimed[index].load(Ordering::SeqCst) {
+                                        thread::spawn(job(index));
+                                    }
+                                }
+                            }
+                            break Outcome::TimedOut;
+                        }
+                        Err(RecvTimeoutError::Disconnected) => unreachable!("a sender is kept"),
+                    }
+                }
+                _ => receiver.recv().expect("a sender is kept"),
+            };
+
+            match event {
+                Event::Started(index, start) => started[index] = Some(start),
+                Event::Finished(index, result, elapsed) => {
+                    finished[index] = Some(match result {
+                        _ if timeout.is_some_and(|timeout| elapsed > timeout) => Outcome::TimedOut,
+                        Some(result) => Outcome::Done(result),
+                        None => Outcome::Panicked,
+                    });
+                }
+            }
+        };
+
+        report(&items[next], outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sleep_ms(ms: u64) -> u64 {
+        thread::sleep(Duration::from_millis(ms));
+        ms
+    }
+
+    fn run(items: &[u64], parallel: bool, timeout: Option<Duration>) -> Vec<Outcome<u64>> {
+        let mut outcomes = Vec::new();
+        run_tasks(items, parallel, timeout, sleep_ms, |_, outcome| {
+            outcomes.push(outcome)
+        });
+        outcomes
+    }
+
+    #[test]
+    fn test_run_tasks_in_order() {
+        for parallel in [false, true] {
+            let outcomes = run(&[30, 0, 10], parallel, None);
+            assert_eq!(
+                outcomes,
+                vec![Outcome::Done(30), Outcome::Done(0), Outcome::Done(10)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_tasks_timeout() {
+        for parallel in [false, true] {
+            let start = Instant::now();
+            let outcomes = run(&[0, 1_000, 10], parallel, Some(Duration::from_millis(200)));
+            assert_eq!(
+                outcomes,
+                vec![Outcome::Done(0), Outcome::TimedOut, Outcome::Done(10)]
+            );
+            assert!(start.elapsed() < Duration::from_millis(800));
+        }
+    }
+
+    #[test]
+    fn test_run_tasks_panic() {
+        for parallel in [false, true] {
+            let mut outcomes = Vec::new();
+            let task = |n: u64| if n == 0 { panic!("boom") } else { n };
+            run_tasks(&[1, 0, 2], parallel, None, task, |_, outcome| {
+                outcomes.push(outcome)
+            });
+            assert_eq!(
+                outcomes,
+                vec![Outcome::Done(1), Outcome::Panicked, Outcome::Done(2)]
+            );
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Searches over graphs given by a function from a node to its neighbors, so they work the same
+//! on grids (with [`Point::neighbors4`](crate::etc::geometry::Point::neighbors4) and a filter)
+//! and on implicit graphs whose nodes are whole states.
+//!
+//! Successor functions return the neighbors of a node, or `(neighbor, cost)` pairs for the
+//! weighted searches. Searches from a single start stop at the first node accepted by `goal`.
+
+use hashbrown::{HashMap, HashSet};
+use pathfinding::num_traits::Zero;
+use pathfinding::prelude as pf;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// A shortest path in number of steps from `start` to a goal, both included.
+pub fn bfs<N, FN, IN>(start: &N, successors: FN, goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    pf::bfs(start, successors, goal)
+}
+
+/// A path from `start` to a goal, found depth first, so not necessarily the shortest one.
+pub fn dfs<N, FN, IN>(start: &N, successors: FN, goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    pf::dfs(start.clone(), successors, goal)
+}
+
+/// A cheapest path from `start` to a goal, and its cost.
+pub fn dijkstra<N, C, FN, IN>(
+    start: &N,
+    successors: FN,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    pf::dijkstra(start, successors, goal)
+}
+
+/// A cheapest path from `start` to a goal, and its cost, exploring first the nodes that
+/// `heuristic` estimates closer to a goal. The estimate must never exceed the actual cost, as
+/// the Manhattan distance does on a grid.
+pub fn astar<N, C, FN, IN>(
+    start: &N,
+    successors: FN,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    pf::astar(start, successors, heuristic, goal)
+}
+
+/// Every cheapest path from `start` to a goal, and their cost. Paths to several goals are all
+/// included when they are equally cheap.
+pub fn all_shortest_paths<N, C, FN, IN>(
+    start: &N,
+    successors: FN,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<Vec<N>>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    pf::astar_bag_collect(start, successors, |_| C::zero(), goal)
+}
+
+/// Every node reachable from `start`, including itself.
+pub fn flood_fill<N, FN, IN>(start: &N, successors: FN) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    pf::bfs_reach(start.clone(), successors).collect()
+}
+
+/// Splits `nodes` into groups of nodes reachable from one another, in the order of the first
+/// node of each group. `neighbors` must be symmetric, as with the regions of a grid.
+pub fn connected_components<N, FN, IN>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbors: FN,
+) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut components = Vec::new();
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = flood_fill(&node, &mut neighbors);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+/// Every node reachable from a start, with its distance from the start and the node it is
+/// reached from on a shortest path.
+#[derive(Clone, Debug)]
+pub struct Reachable<N, C> {
+    pub distances: HashMap<N, C>,
+    /// The previous node on a shortest path, for every node except the start.
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, C> Reachable<N, C> {
+    /// A shortest path from the start to `node`, both included, if it is reachable.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().expect("never empty")) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The distance in number of steps to every node reachable from `start`.
+pub fn bfs_all<N, FN, IN>(start: &N, mut successors: FN) -> Reachable<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut reachable = Reachable {
+        distances: HashMap::from([(start.clone(), 0)]),
+        predecessors: HashMap::new(),
+    };
+    let mut queue = VecDeque::from([(start.clone(), 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if reachable.distances.contains_key(&next) {
+                continue;
+            }
+            reachable.distances.insert(next.clone(), distance + 1);
+            reachable.predecessors.insert(next.clone(), node.clone());
+            queue.push_back((next, distance + 1));
+        }
+    }
+
+    reachable
+}
+
+/// The cost of the cheapest path to every node reachable from `start`.
+pub fn dijkstra_all<N, C, FN, IN>(start: &N, successors: FN) -> Reachable<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut reachable = Reachable {
+        distances: HashMap::from([(start.clone(), C::zero())]),
+        predecessors: HashMap::new(),
+    };
+    for (node, (previous, cost)) in pf::dijkstra_all(start, successors) {
+        reachable.distances.insert(node.clone(), cost);
+        reachable.predecessors.insert(node, previous);
+    }
+    reachable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::geometry::Point;
+    use crate::etc::grid::{parse_grid, GridExt};
+    use grid::Grid;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+#.##";
+
+    fn open(grid: &Grid<char>, point: Point) -> Vec<Point> {
+        point
+            .neighbors4(grid)
+            .filter(|&neighbor| grid.at(neighbor) != Some(&'#'))
+            .collect()
+    }
+
+    #[test]
+    fn test_paths() {
+        let grid = parse_grid(MAZE).unwrap();
+        let (start, end) = (Point::new(0, 0), Point::new(2, 3));
+        let is_end = |point: &Point| *point == end;
+
+        let path = bfs(&start, |&point| open(&grid, point), is_end).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (start, end));
+        assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+
+        let path = dfs(&start, |&point| open(&grid, point), is_end).unwrap();
+        assert_eq!(path.last(), Some(&end));
+        assert_eq!(
+            bfs(&start, |&point| open(&grid, point), |point| point.row == 5),
+            None
+        );
+
+        // Entering the outer columns costs more, so the path through the middle is cheapest
+        let weighted = |&point: &Point| {
+            open(&grid, point)
+                .into_iter()
+                .map(|next| (next, if next.col % 3 == 0 { 10 } else { 1 }))
+        };
+        let (path, cost) = dijkstra(&start, weighted, is_end).unwrap();
+        assert_eq!(cost, 14);
+        assert_eq!(path[4], Point::new(2, 2));
+        assert_eq!(
+            astar(&start, weighted, |point| point.manhattan(end), is_end),
+            Some((path, cost))
+        );
+
+        let (paths, cost) = all_shortest_paths(
+            &start,
+            |&point| open(&grid, point).into_iter().map(|next| (next, 1)),
+            is_end,
+        )
+        .unwrap();
+        assert_eq!(cost, 5);
+        assert_eq!(paths.len(), 3);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let grid = parse_grid(MAZE).unwrap();
+        let start = Point::new(0, 0);
+
+        let reachable = bfs_all(&start, |&point| open(&grid, point));
+        assert_eq!(reachable.distances.len(), 11);
+        assert_eq!(reachable.distances[&Point::new(3, 1)], 4);
+        let path = reachable.path_to(&Point::new(2, 3)).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!(path[0], start);
+        assert_eq!(reachable.path_to(&Point::new(1, 1)), None);
+
+        let reachable = dijkstra_all(&start, |&point| {
+            open(&grid, point).into_iter().map(|next| (next, 2))
+        });
+        assert_eq!(reachable.distances[&start], 0);
+        assert_eq!(reachable.distances[&Point::new(3, 1)], 8);
+        assert_eq!(reachable.path_to(&start), Some(vec![start]));
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = parse_grid("aab\nbab\nbba").unwrap();
+        let same = |point: &Point| {
+            point
+                .neighbors4(&grid)
+                .filter(|&neighbor| grid.at(neighbor) == grid.at(*point))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(flood_fill(&Point::new(0, 0), same).len(), 3);
+        let components = connected_components(
+            grid.indexed_iter()
+                .map(|(position, _)| Point::from(position)),
+            same,
+        );
+        let sizes: Vec<usize> = components.iter().map(HashSet::len).collect();
+        assert_eq!(sizes, vec![3, 2, 3, 1]);
+    }
+}
@@ -1,8 +1,29 @@
+use grid::Grid;
+use num_bigint::BigInt;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
 use Solution::*;
 
-#[derive(Clone, PartialEq, Eq)]
+/// The answer to a part of a puzzle.
+///
+/// Integer answers compare, order and hash by their value whatever their type, so
+/// `Solution::from(5u32) == Solution::from(5u64)`. Strings and multi-line answers likewise compare
+/// by their text, so an answer drawn on a single line is the same as its recorded string. Unsolved
+/// parts come before every integer, then text answers.
+#[derive(Clone, Debug)]
 pub enum Solution {
+    /// The part has not been implemented yet. Unlike any placeholder value,
+    /// this can never be mistaken for a genuine answer.
+    Unsolved,
     I8(i8),
     I16(i16),
     I32(i32),
@@ -15,12 +36,18 @@ pub enum Solution {
     U64(u64),
     U128(u128),
     Usize(usize),
+    /// An integer too large for the primitive types.
+    Big(BigInt),
     Str(String),
+    /// An answer drawn over several lines, such as ASCII art on a grid. Lines are displayed
+    /// separated by `\n`, without a final one.
+    Lines(Vec<String>),
 }
 
 impl Display for Solution {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
+            Unsolved => "unsolved".fmt(f),
             I8(x) => x.fmt(f),
             I16(x) => x.fmt(f),
             I32(x) => x.fmt(f),
@@ -33,7 +60,91 @@ impl Display for Solution {
             U64(x) => x.fmt(f),
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
+            Big(x) => x.fmt(f),
             Str(x) => x.fmt(f),
+            Lines(x) => x.join("\n").fmt(f),
+        }
+    }
+}
+
+impl Solution {
+    pub fn is_unsolved(&self) -> bool {
+        matches!(self, Unsolved)
+    }
+
+    /// Whether the answer is an integer, of any type.
+    pub fn is_integer(&self) -> bool {
+        self.integer().is_some()
+    }
+
+    /// Whether `answer`, as recorded in an answers file or a fixture, is this solution.
+    pub fn matches(&self, answer: &str) -> bool {
+        answer
+            .parse::<Solution>()
+            .is_ok_and(|answer| answer == *self)
+    }
+
+    /// The sum of two integer answers, or `None` if either is not an integer. The sum keeps the
+    /// type of the operands when they have the same one and it fits.
+    pub fn checked_add(&self, other: &Solution) -> Option<Solution> {
+        let sum = self.integer()? + other.integer()?;
+        let same_kind = self.kind() == other.kind();
+        Some(
+            same_kind
+                .then(|| with_kind(&sum, self.kind()))
+                .flatten()
+                .unwrap_or_else(|| narrowest(sum)),
+        )
+    }
+
+    // The value of an integer answer
+    fn integer(&self) -> Option<BigInt> {
+        Some(match self {
+            I8(x) => BigInt::from(*x),
+            I16(x) => BigInt::from(*x),
+            I32(x) => BigInt::from(*x),
+            I64(x) => BigInt::from(*x),
+            I128(x) => BigInt::from(*x),
+            Isize(x) => BigInt::from(*x),
+            U8(x) => BigInt::from(*x),
+            U16(x) => BigInt::from(*x),
+            U32(x) => BigInt::from(*x),
+            U64(x) => BigInt::from(*x),
+            U128(x) => BigInt::from(*x),
+            Usize(x) => BigInt::from(*x),
+            Big(x) => x.clone(),
+            Unsolved | Str(_) | Lines(_) => return None,
+        })
+    }
+
+    // The displayed text of a string or multi-line answer
+    fn text(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Str(x) => Some(Cow::Borrowed(x)),
+            Lines(x) => Some(Cow::Owned(x.join("\n"))),
+            _ => None,
+        }
+    }
+
+    /// The name of the variant holding the answer, such as `U64` or `Str`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Unsolved => "Unsolved",
+            I8(_) => "I8",
+            I16(_) => "I16",
+            I32(_) => "I32",
+            I64(_) => "I64",
+            I128(_) => "I128",
+            Isize(_) => "Isize",
+            U8(_) => "U8",
+            U16(_) => "U16",
+            U32(_) => "U32",
+            U64(_) => "U64",
+            U128(_) => "U128",
+            Usize(_) => "Usize",
+            Big(_) => "Big",
+            Str(_) => "Str",
+            Lines(_) => "Lines",
         }
     }
 }
@@ -45,7 +156,7 @@ macro_rules! impl_from {
                 Self::$kind_(sol)
             }
         }
-    }
+    };
 }
 
 impl_from!(i8, I8);
@@ -60,6 +171,7 @@ impl_from!(u32, U32);
 impl_from!(u64, U64);
 impl_from!(u128, U128);
 impl_from!(usize, Usize);
+impl_from!(BigInt, Big);
 impl_from!(String, Str);
 
 impl From<&str> for Solution {
@@ -67,3 +179,426 @@ impl From<&str> for Solution {
         Self::Str(sol.to_owned())
     }
 }
+
+impl From<Vec<String>> for Solution {
+    fn from(sol: Vec<String>) -> Self {
+        Self::Lines(sol)
+    }
+}
+
+/// A grid of characters, one line per row.
+impl From<&Grid<char>> for Solution {
+    fn from(sol: &Grid<char>) -> Self {
+        Self::Lines(sol.iter_rows().map(|row| row.collect()).collect())
+    }
+}
+
+// The value as a solution of the variant named `kind`, if it fits
+fn with_kind(x: &BigInt, kind: &str) -> Option<Solution> {
+    macro_rules! convert {
+        ($($type_:ident, $kind_:ident);*) => {
+            match kind {
+                $(stringify!($kind_) => $type_::try_from(x).ok().map(Solution::from),)*
+                "Big" => Some(Big(x.clone())),
+                _ => None,
+            }
+        };
+    }
+    convert!(i8, I8; i16, I16; i32, I32; i64, I64; i128, I128; isize, Isize;
+        u8, U8; u16, U16; u32, U32; u64, U64; u128, U128; usize, Usize)
+}
+
+// The value as the narrowest of `I64`/`U64`, `I128`/`U128` and `Big` holding it
+fn narrowest(x: BigInt) -> Solution {
+    with_kind(&x, "U64")
+        .or_else(|| with_kind(&x, "I64"))
+        .or_else(|| with_kind(&x, "U128"))
+        .or_else(|| with_kind(&x, "I128"))
+        .unwrap_or(Big(x))
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Solution {}
+
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Solution {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Unsolved, then integers, then text
+        let rank = |solution: &Solution| match solution {
+            Unsolved => 0,
+            Str(_) | Lines(_) => 2,
+            _ => 1,
+        };
+        match (self.text(), other.text()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => match (self.integer(), other.integer()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                _ => rank(self).cmp(&rank(other)),
+            },
+        }
+    }
+}
+
+impl Hash for Solution {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Unsolved => 0.hash(state),
+            Str(_) | Lines(_) => self.text().hash(state),
+            _ => self.integer().hash(state),
+        }
+    }
+}
+
+/// Parses an answer as it is displayed: an integer if it is written exactly as one (without a
+/// sign or leading zeros), multi-line if it has several lines, else a string. An empty answer is
+/// a string too.
+impl FromStr for Solution {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.contains('\n') {
+            return Ok(Lines(s.split('\n').map(str::to_owned).collect()));
+        }
+        Ok(match s.parse::<BigInt>() {
+            Ok(x) if x.to_string() == s => narrowest(x),
+            _ => Str(s.to_owned()),
+        })
+    }
+}
+
+/// Why a solution could not be converted to a primitive type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionError {
+    pub solution: Solution,
+    pub target: &'static str,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.solution {
+            Unsolved => write!(f, "cannot convert an unsolved answer to {}", self.target),
+            _ => write!(
+                f,
+                "cannot convert the {} answer {} to {}",
+                self.solution.kind(),
+                self.solution,
+                self.target
+            ),
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+macro_rules! impl_try_from {
+    ($type_:ident) => {
+        impl TryFrom<Solution> for $type_ {
+            type Error = ConversionError;
+
+            fn try_from(sol: Solution) -> std::result::Result<Self, Self::Error> {
+                sol.integer()
+                    .and_then(|x| $type_::try_from(x).ok())
+                    .ok_or(ConversionError {
+                        solution: sol,
+                        target: stringify!($type_),
+                    })
+            }
+        }
+    };
+}
+
+impl_try_from!(i8);
+impl_try_from!(i16);
+impl_try_from!(i32);
+impl_try_from!(i64);
+impl_try_from!(i128);
+impl_try_from!(isize);
+impl_try_from!(u8);
+impl_try_from!(u16);
+impl_try_from!(u32);
+impl_try_from!(u64);
+impl_try_from!(u128);
+impl_try_from!(usize);
+impl_try_from!(BigInt);
+
+/// Adds two integer answers, as for the total of several days. An unsolved operand makes the sum
+/// unsolved.
+///
+/// # Panics
+///
+/// If an operand is not an integer.
+impl Add for Solution {
+    type Output = Solution;
+
+    fn add(self, other: Solution) -> Solution {
+        if self.is_unsolved() || other.is_unsolved() {
+            return Unsolved;
+        }
+        self.checked_add(&other).unwrap_or_else(|| {
+            panic!(
+                "cannot add the {} answer {} and the {} answer {}",
+                self.kind(),
+                self,
+                other.kind(),
+                other
+            )
+        })
+    }
+}
+
+/// The sum of the answers, `0` if there are none.
+impl Sum for Solution {
+    fn sum<I: Iterator<Item = Solution>>(iter: I) -> Solution {
+        iter.reduce(Add::add).unwrap_or(U64(0))
+    }
+}
+
+/// Answers are stored as they are displayed, since the larger integer types fit in neither a JSON
+/// nor a TOML number. An unsolved part is stored as nothing at all.
+#[cfg(feature = "serde")]
+impl Serialize for Solution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Unsolved => serializer.serialize_none(),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+/// Reads an answer stored as a string, or written by hand as a number. A missing answer, as an
+/// unsolved part is in TOML, is unsolved.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Solution {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_option(SolutionVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct SolutionVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for SolutionVisitor {
+    type Value = Solution;
+
+    fn expecting(&self, f: &mut Formatter) -> Result {
+        write!(f, "an answer")
+    }
+
+    fn visit_str<E: de::Error>(self, answer: &str) -> std::result::Result<Solution, E> {
+        let Ok(answer) = answer.parse();
+        Ok(answer)
+    }
+
+    fn visit_i64<E: de::Error>(self, answer: i64) -> std::result::Result<Solution, E> {
+        Ok(I64(answer))
+    }
+
+    fn visit_u64<E: de::Error>(self, answer: u64) -> std::result::Result<Solution, E> {
+        Ok(U64(answer))
+    }
+
+    fn visit_i128<E: de::Error>(self, answer: i128) -> std::result::Result<Solution, E> {
+        Ok(I128(answer))
+    }
+
+    fn visit_u128<E: de::Error>(self, answer: u128) -> std::result::Result<Solution, E> {
+        Ok(U128(answer))
+    }
+
+    fn visit_none<E: de::Error>(self) -> std::result::Result<Solution, E> {
+        Ok(Unsolved)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> std::result::Result<Solution, E> {
+        Ok(Unsolved)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<Solution, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hashbrown::HashSet;
+
+    #[test]
+    fn test_cross_variant_equality() {
+        assert_eq!(Solution::from(5u32), Solution::from(5u64));
+        assert_eq!(Solution::from(-5i8), Solution::from(-5i128));
+        assert_ne!(Solution::from(5u32), Solution::from(-5i32));
+        assert_ne!(Solution::from(5u32), Solution::from("5"));
+        assert_eq!(Solution::Unsolved, Solution::Unsolved);
+
+        let set: HashSet<Solution> = [Solution::from(7u8), Solution::from(7usize)].into();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut solutions = vec![
+            Solution::from("abc"),
+            Solution::from(u128::MAX),
+            Solution::from(3u8),
+            Solution::Unsolved,
+            Solution::from(i128::MIN),
+            Solution::from(-1i32),
+        ];
+        solutions.sort();
+        assert_eq!(
+            solutions,
+            vec![
+                Solution::Unsolved,
+                Solution::from(i128::MIN),
+                Solution::from(-1i32),
+                Solution::from(3u8),
+                Solution::from(u128::MAX),
+                Solution::from("abc"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(u64::try_from(Solution::from(5i32)), Ok(5));
+        assert_eq!(i8::try_from(Solution::from(-128i64)), Ok(-128));
+        assert!(u8::try_from(Solution::from(256u16)).is_err());
+        assert!(u64::try_from(Solution::from(-1i8)).is_err());
+        assert_eq!(
+            usize::try_from(Solution::from("ab"))
+                .unwrap_err()
+                .to_string(),
+            "cannot convert the Str answer ab to usize"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let parse = |s: &str| s.parse::<Solution>().unwrap();
+        assert_eq!(parse("42"), Solution::from(42u8));
+        assert_eq!(parse("-42"), Solution::from(-42i64));
+        assert_eq!(
+            parse("340282366920938463463374607431768211455"),
+            Solution::from(u128::MAX)
+        );
+        assert_eq!(parse("007"), Solution::from("007"));
+        assert_eq!(parse("+7"), Solution::from("+7"));
+        assert_eq!(parse("-0"), Solution::from("-0"));
+        assert_eq!(parse("EFJ"), Solution::from("EFJ"));
+        assert!(Solution::from(17u16).matches("17"));
+        assert!(!Solution::from(17u16).matches("017"));
+    }
+
+    #[test]
+    fn test_big() {
+        let big = Solution::from(BigInt::from(u128::MAX) * 10);
+        assert_eq!(big.to_string(), "3402823669209384634633746074317682114550");
+        assert_eq!(big.to_string().parse::<Solution>().unwrap(), big);
+        assert!(big > Solution::from(u128::MAX));
+        assert_eq!(Solution::from(BigInt::from(-3)), Solution::from(-3i8));
+        assert_eq!(i8::try_from(Solution::from(BigInt::from(-3))), Ok(-3));
+        assert!(u128::try_from(big.clone()).is_err());
+        assert_eq!(BigInt::try_from(Solution::from(7u8)), Ok(BigInt::from(7)));
+        assert_eq!(
+            (big.clone() + Solution::from(BigInt::from(1))).kind(),
+            "Big"
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Grid::from_vec("#..##.#.".chars().collect(), 4);
+        let art = Solution::from(&grid);
+        assert_eq!(art.to_string(), "#..#\n#.#.");
+        assert_eq!(art.kind(), "Lines");
+        assert_eq!("#..#\n#.#.".parse::<Solution>().unwrap(), art);
+        assert!(art.matches("#..#\n#.#."));
+        assert!(!art.matches("#..#"));
+        assert!(art > Solution::from("#..#"));
+        assert!(Solution::from(1u8).checked_add(&art).is_none());
+
+        // An answer on one line is the same as its recorded text, and the other way round
+        let row = Solution::from(&Grid::from_vec("#..#".chars().collect(), 4));
+        assert_eq!(row.kind(), "Lines");
+        assert!(row.matches(&row.to_string()));
+        assert_eq!(row, Solution::from("#..#"));
+        let text = Solution::from("a\nb");
+        assert!(text.matches(&text.to_string()));
+        assert_eq!(text, Solution::from(vec!["a".to_owned(), "b".to_owned()]));
+        let set: HashSet<Solution> = [row, Solution::from("#..#")].into();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_sum() {
+        let sum = Solution::from(200u8) + Solution::from(100u8);
+        assert_eq!(sum, Solution::from(300u64));
+        assert_eq!(sum.kind(), "U64");
+        assert_eq!((Solution::from(2u8) + Solution::from(3u8)).kind(), "U8");
+        assert_eq!(
+            Solution::from(-7i32) + Solution::from(5u64),
+            Solution::from(-2i8)
+        );
+        assert_eq!(
+            Solution::from(i128::MIN) + Solution::from(u128::MAX),
+            Solution::from(i128::MAX)
+        );
+        assert_eq!(
+            Solution::from(i128::MIN) + Solution::from(0u8),
+            Solution::from(i128::MIN)
+        );
+        assert_eq!(Solution::from(1u8) + Solution::Unsolved, Solution::Unsolved);
+        let big = Solution::from(u128::MAX) + Solution::from(1u8);
+        assert_eq!(big.kind(), "Big");
+        assert_eq!(big.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(Solution::from("a").checked_add(&Solution::from(1u8)), None);
+
+        let total: Solution = [1u32, 2, 3].into_iter().map(Solution::from).sum();
+        assert_eq!(total, Solution::from(6u32));
+        assert_eq!(total.kind(), "U32");
+        assert_eq!(
+            std::iter::empty::<Solution>().sum::<Solution>(),
+            Solution::from(0u8)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let solutions = vec![
+            Solution::from(u128::MAX),
+            Solution::from(-3i8),
+            Solution::from("EFJ"),
+            "#..#\n.##.".parse().unwrap(),
+            Solution::Unsolved,
+        ];
+        let json = serde_json::to_string(&solutions).unwrap();
+        assert_eq!(
+            json,
+            r##"["340282366920938463463374607431768211455","-3","EFJ","#..#\n.##.",null]"##
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<Solution>>(&json).unwrap(),
+            solutions
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<Solution>>("[42, -1]").unwrap(),
+            vec![Solution::from(42u8), Solution::from(-1i8)]
+        );
+    }
+}
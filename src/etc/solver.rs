@@ -0,0 +1,215 @@
+use crate::etc::cli::Part;
+use crate::etc::error::{SolveError, SolveResult};
+use crate::etc::solution::Solution;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// The solution to one day of an event.
+///
+/// The input text is parsed once, then each part solves the parsed input. Days without a parse
+/// step use `&str` as their input and return it unchanged from `parse`.
+///
+/// Each day registers its solver with `inventory::submit! { DaySolver::new::<DayNN>() }`.
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Title of the puzzle
+    const NAME: &'static str;
+    /// The parts that have a solution. The others must answer [`Solution::Unsolved`], which
+    /// [`DaySolver::solve`] checks so that a solved part cannot be left out of the list unnoticed.
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError>;
+    fn part1(input: &Self::Input<'_>) -> SolveResult;
+    fn part2(input: &Self::Input<'_>) -> SolveResult;
+}
+
+/// The answer to one part of a day, and how long it took to compute.
+pub struct PartResult {
+    pub part: Part,
+    pub solution: Solution,
+    pub elapsed_ms: f64,
+}
+
+/// The parts of a day solved by [`DaySolver::solve`], and how long parsing the input took.
+pub struct Solved {
+    pub parse_ms: f64,
+    pub parts: Vec<PartResult>,
+}
+
+/// A registered [`Solver`], with its type erased so that every day can be listed together.
+pub struct DaySolver {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub parts: &'static [Part],
+    solve: fn(&str, &[Part]) -> Result<Solved, SolveError>,
+}
+
+inventory::collect!(DaySolver);
+
+impl DaySolver {
+    pub const fn new<S: Solver>() -> Self {
+        DaySolver {
+            year: S::YEAR,
+            day: S::DAY,
+            name: S::NAME,
+            parts: S::PARTS,
+            solve: solve::<S>,
+        }
+    }
+
+    pub fn implements(&self, part: Part) -> bool {
+        self.parts.contains(&part)
+    }
+
+    /// Parses the input and solves the given parts, timing each step. Parts without a solution are
+    /// left out of the result, and an answer from a part that is not listed is an error.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Solved, SolveError> {
+        (self.solve)(input, parts)
+    }
+}
+
+fn solve<S: Solver>(input: &str, parts: &[Part]) -> Result<Solved, SolveError> {
+    let time = Instant::now();
+    let parsed = S::parse(black_box(input))?;
+    let parse_ms = elapsed_ms(time);
+
+    let mut solved = Vec::new();
+    for &part in parts {
+        let time = Instant::now();
+        let solution = match part {
+            Part::One => S::part1(black_box(&parsed)),
+            Part::Two => S::part2(black_box(&parsed)),
+        }?;
+        let elapsed_ms = elapsed_ms(time);
+
+        if !S::PARTS.contains(&part) {
+            if solution.is_unsolved() {
+                continue;
+            }
+            return Err(SolveError::Unlisted(part));
+        }
+        solved.push(PartResult {
+            part,
+            solution: black_box(solution),
+            elapsed_ms,
+        });
+    }
+
+    Ok(Solved {
+        parse_ms,
+        parts: solved,
+    })
+}
+
+/// Every registered solver, ordered by year and day.
+pub fn solvers() -> Vec<&'static DaySolver> {
+    let mut solvers: Vec<_> = inventory::iter::<DaySolver>.into_iter().collect();
+    solvers.sort_unstable_by_key(|solver| (solver.year, solver.day));
+    solvers
+}
+
+/// The years with at least one registered solver, in ascending order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = solvers().iter().map(|solver| solver.year).collect();
+    years.dedup();
+    years
+}
+
+pub fn find_solver(year: u16, day: u8) -> Result<&'static DaySolver, SolveError> {
+    inventory::iter::<DaySolver>
+        .into_iter()
+        .find(|solver| (solver.year, solver.day) == (year, day))
+        .ok_or(SolveError::InvalidDay(year, day))
+}
+
+pub fn elapsed_ms(time: Instant) -> f64 {
+    time.elapsed().as_nanos() as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solver for Example {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 26;
+        const NAME: &'static str = "Example";
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> SolveResult {
+            Ok(Solution::from(input.len()))
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> SolveResult {
+            Ok(Solution::Unsolved)
+        }
+    }
+
+    // The same day, with part 1 solved but not listed
+    struct Unlisted;
+
+    impl Solver for Unlisted {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 26;
+        const NAME: &'static str = "Example";
+        const PARTS: &'static [Part] = &[];
+
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+            Example::parse(input)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> SolveResult {
+            Example::part1(input)
+        }
+
+        fn part2(input: &Self::Input<'_>) -> SolveResult {
+            Example::part2(input)
+        }
+    }
+
+    #[test]
+    fn test_solve_implemented_parts() {
+        let solver = DaySolver::new::<Example>();
+        assert!(solver.implements(Part::One) && !solver.implements(Part::Two));
+
+        let solved = solver.solve("a\nb\nc", &[Part::One, Part::Two]).unwrap();
+        assert_eq!(solved.parts.len(), 1);
+        assert_eq!(solved.parts[0].solution.to_string(), "3");
+
+        let unlisted = DaySolver::new::<Unlisted>();
+        assert!(unlisted.solve("a", &[Part::Two]).unwrap().parts.is_empty());
+        assert!(matches!(
+            unlisted.solve("a", &[Part::One, Part::Two]),
+            Err(SolveError::Unlisted(Part::One))
+        ));
+    }
+
+    #[test]
+    fn test_registry() {
+        let days: Vec<(u16, u8)> = solvers().iter().map(|s| (s.year, s.day)).collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!((1..=25).all(|day| days.contains(&(2024, day))));
+        let years = years();
+        assert!(years.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(years.contains(&2024));
+        assert_eq!(find_solver(2024, 6).unwrap().name, "Guard Gallivant");
+        assert!(matches!(
+            find_solver(2024, 26),
+            Err(SolveError::InvalidDay(2024, 26))
+        ));
+    }
+}
@@ -0,0 +1,450 @@
+use crate::etc::answers::Verdict;
+use crate::etc::cli::Part;
+use crate::Solution;
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+use clap::ValueEnum;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, read_to_string, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const HEADER: &str = "timestamp,part,result,retry_at,answer";
+
+/// How the website judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Judgement {
+    Correct,
+    /// Wrong, without a hint about the right answer.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Not judged, because the previous answer was submitted too recently.
+    TooSoon,
+    /// Not judged, because the part is already solved.
+    AlreadySolved,
+}
+
+impl Judgement {
+    const ALL: [Judgement; 6] = [
+        Judgement::Correct,
+        Judgement::Wrong,
+        Judgement::TooHigh,
+        Judgement::TooLow,
+        Judgement::TooSoon,
+        Judgement::AlreadySolved,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Judgement::Correct => "correct",
+            Judgement::Wrong => "wrong",
+            Judgement::TooHigh => "too high",
+            Judgement::TooLow => "too low",
+            Judgement::TooSoon => "too soon",
+            Judgement::AlreadySolved => "already solved",
+        }
+    }
+
+    /// Whether the answer is known to be wrong.
+    pub fn is_rejection(self) -> bool {
+        matches!(
+            self,
+            Judgement::Wrong | Judgement::TooHigh | Judgement::TooLow
+        )
+    }
+}
+
+impl Display for Judgement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The judgement of a submitted answer, and how long to wait before submitting another one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub judgement: Judgement,
+    pub wait: Option<Duration>,
+}
+
+/// Reads the reply of the website to a submitted answer from its HTML page.
+pub fn parse_reply(html: &str) -> Result<Reply, String> {
+    let text = article_text(html);
+    let judgement = if text.contains("That's the right answer") {
+        Judgement::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Judgement::TooSoon
+    } else if text.contains("Did you already complete it") {
+        Judgement::AlreadySolved
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Judgement::TooHigh
+        } else if text.contains("your answer is too low") {
+            Judgement::TooLow
+        } else {
+            Judgement::Wrong
+        }
+    } else {
+        let first_line = text.trim().lines().next().unwrap_or_default();
+        return Err(format!("unexpected reply: {}", first_line));
+    };
+
+    Ok(Reply {
+        judgement,
+        wait: parse_wait(&text),
+    })
+}
+
+// The message is the text of the only <article> of the page, without its markup
+fn article_text(html: &str) -> String {
+    // Starting right after `<article`, that is still inside its tag
+    let (article, mut in_tag) = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((html, false), |(article, _)| (article, true));
+
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+// Either "You have 1m 23s left to wait" after submitting too soon, or "Please wait 5 minutes
+// before trying again" after a wrong answer
+fn parse_wait(text: &str) -> Option<Duration> {
+    let text = text.to_lowercase();
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("you have ")?;
+        return left
+            .split_whitespace()
+            .map(|amount| {
+                let unit = match amount.chars().last()? {
+                    'h' => 3600,
+                    'm' => 60,
+                    's' => 1,
+                    _ => return None,
+                };
+                let count: u64 = amount[..amount.len() - 1].parse().ok()?;
+                Some(count * unit)
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let (_, rest) = text.split_once("please wait ")?;
+    let (wait, _) = rest.split_once(" before trying again")?;
+    let (count, unit) = wait.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    let unit = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        _ => return None,
+    };
+    Some(Duration::from_secs(count * unit))
+}
+
+/// One answer submitted to the website.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    pub timestamp: DateTime<Utc>,
+    pub part: Part,
+    pub judgement: Judgement,
+    /// No answer to this day may be submitted before then.
+    pub retry_at: Option<DateTime<Utc>>,
+    pub answer: String,
+}
+
+impl Attempt {
+    pub fn new(timestamp: DateTime<Utc>, part: Part, answer: String, reply: &Reply) -> Self {
+        Attempt {
+            timestamp,
+            part,
+            judgement: reply.judgement,
+            retry_at: reply
+                .wait
+                .and_then(|wait| TimeDelta::from_std(wait).ok())
+                .map(|wait| timestamp + wait),
+            answer,
+        }
+    }
+}
+
+/// Every answer submitted for a day, as recorded in `answers/YYYY/dayNN.attempts`.
+#[derive(Default, Debug, PartialEq)]
+pub struct Attempts(pub Vec<Attempt>);
+
+pub fn attempts_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("answers/{}/day{:02}.attempts", year, day))
+}
+
+/// Reads the submitted answers of a day, oldest first. A missing file means nothing was submitted.
+pub fn load_attempts(path: &Path) -> io::Result<Attempts> {
+    let text = match read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Attempts::default()),
+        Err(err) => return Err(err),
+    };
+
+    text.lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            parse_attempt(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed attempt", path.display(), number + 1),
+                )
+            })
+        })
+        .collect::<io::Result<_>>()
+        .map(Attempts)
+}
+
+/// Appends an attempt to the file of its day, creating it with a header if needed.
+/// The answer must fit on one line, as each attempt is a line of the file.
+pub fn append_attempt(path: &Path, attempt: &Attempt) -> io::Result<()> {
+    if attempt.answer.contains(['\n', '\r']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a multi-line answer cannot be recorded",
+        ));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    writeln!(
+        file,
+        "{},{},{},{},{}",
+        attempt.timestamp.to_rfc3339_opts(SecondsFormat::Secs, true),
+        attempt.part,
+        attempt.judgement,
+        attempt
+            .retry_at
+            .map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true))
+            .unwrap_or_default(),
+        attempt.answer
+    )
+}
+
+// The answer comes last, as it may contain commas itself
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(5, ',');
+    Some(Attempt {
+        timestamp: fields.next()?.parse().ok()?,
+        part: Part::from_str(fields.next()?, false).ok()?,
+        judgement: fields.next().and_then(|name| {
+            Judgement::ALL
+                .into_iter()
+                .find(|judgement| judgement.name() == name)
+        })?,
+        retry_at: match fields.next()? {
+            "" => None,
+            time => Some(time.parse().ok()?),
+        },
+        answer: fields.next()?.to_owned(),
+    })
+}
+
+impl Attempts {
+    /// The end of the cooldown after the latest attempt, if it has not passed at `now`.
+    pub fn cooldown(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.0
+            .iter()
+            .filter_map(|attempt| attempt.retry_at)
+            .max()
+            .filter(|&retry_at| retry_at > now)
+    }
+
+    /// Compares an answer against the submitted ones: the accepted answer if there is one,
+    /// otherwise every rejected answer and the bounds given by those that were too high or low.
+    pub fn verify(&self, part: Part, solution: &Solution) -> Verdict {
+        if solution.is_unsolved() {
+            return Verdict::Unknown;
+        }
+        let attempts = self.0.iter().filter(|attempt| attempt.part == part);
+
+        if let Some(correct) = attempts
+            .clone()
+            .find(|attempt| attempt.judgement == Judgement::Correct)
+        {
+            return if solution.matches(&correct.answer) {
+                Verdict::Pass
+            } else {
+                Verdict::Fail {
+                    expected: correct.answer.clone(),
+                }
+            };
+        }
+
+        for attempt in attempts.filter(|attempt| attempt.judgement.is_rejection()) {
+            let Ok(bound) = attempt.answer.parse::<Solution>();
+            let comparable = solution.is_integer() && bound.is_integer();
+            let reason = match attempt.judgement {
+                _ if bound == *solution => "was rejected before".to_owned(),
+                Judgement::TooHigh if comparable && *solution >= bound => format!(
+                    "is not lower than the rejected {}, which is too high",
+                    bound
+                ),
+                Judgement::TooLow if comparable && *solution <= bound => format!(
+                    "is not higher than the rejected {}, which is too low",
+                    bound
+                ),
+                _ => continue,
+            };
+            return Verdict::Rejected {
+                reason: format!("{} {}", solution, reason),
+            };
+        }
+
+        Verdict::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(message: &str) -> Reply {
+        let html = format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            message
+        );
+        parse_reply(&html).unwrap()
+    }
+
+    fn attempt(part: Part, judgement: Judgement, answer: &str) -> Attempt {
+        Attempt {
+            timestamp: "2024-12-06T05:10:00Z".parse().unwrap(),
+            part,
+            judgement,
+            retry_at: None,
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(
+            reply("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+            Reply {
+                judgement: Judgement::Correct,
+                wait: None
+            }
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/6\">[Return to Day 6]</a>"),
+            Reply {
+                judgement: Judgement::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            reply("That's not the right answer.  If you're stuck, please wait 5 minutes before trying again.").judgement,
+            Judgement::Wrong
+        );
+        assert_eq!(
+            reply("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."),
+            Reply {
+                judgement: Judgement::TooSoon,
+                wait: Some(Duration::from_secs(83))
+            }
+        );
+        assert_eq!(
+            reply("You don't seem to be solving the right level.  Did you already complete it?")
+                .judgement,
+            Judgement::AlreadySolved
+        );
+        assert!(parse_reply("<article><p>Hello</p></article>").is_err());
+    }
+
+    #[test]
+    fn test_attempts_round_trip() {
+        let timestamp = "2024-12-06T05:10:00Z".parse().unwrap();
+        let wrong = Reply {
+            judgement: Judgement::TooLow,
+            wait: Some(Duration::from_secs(60)),
+        };
+        let attempts = vec![
+            Attempt::new(timestamp, Part::One, "41".to_owned(), &wrong),
+            attempt(Part::Two, Judgement::Wrong, "co,de,ka"),
+        ];
+
+        let path = std::env::temp_dir().join(format!("aoc-attempts-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        for attempt in &attempts {
+            append_attempt(&path, attempt).unwrap();
+        }
+        let drawn = attempt(Part::One, Judgement::Wrong, "#..#\n#..#");
+        assert!(append_attempt(&path, &drawn).is_err());
+        let loaded = load_attempts(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, Attempts(attempts));
+        assert_eq!(
+            loaded.cooldown("2024-12-06T05:10:59Z".parse().unwrap()),
+            Some("2024-12-06T05:11:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            loaded.cooldown("2024-12-06T05:11:00Z".parse().unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_verify_against_attempts() {
+        let attempts = Attempts(vec![
+            attempt(Part::One, Judgement::TooHigh, "500"),
+            attempt(Part::One, Judgement::TooLow, "100"),
+            attempt(Part::One, Judgement::Wrong, "abc"),
+            attempt(Part::Two, Judgement::Correct, "7"),
+        ]);
+
+        assert_eq!(
+            attempts.verify(Part::One, &Solution::from(99)),
+            Verdict::Rejected {
+                reason: "99 is not higher than the rejected 100, which is too low".to_owned()
+            }
+        );
+        assert!(matches!(
+            attempts.verify(Part::One, &Solution::from(500u64)),
+            Verdict::Rejected { .. }
+        ));
+        assert!(matches!(
+            attempts.verify(Part::One, &Solution::from("abc")),
+            Verdict::Rejected { .. }
+        ));
+        assert_eq!(
+            attempts.verify(Part::One, &Solution::from(250)),
+            Verdict::Unknown
+        );
+
+        assert_eq!(
+            attempts.verify(Part::Two, &Solution::from(7)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            attempts.verify(Part::Two, &Solution::from(8)),
+            Verdict::Fail {
+                expected: "7".to_owned()
+            }
+        );
+    }
+}
@@ -1,154 +1,766 @@
-#![allow(unused_imports)]
-mod days;
 mod etc;
+mod y2024;
 
 use chrono::prelude::*;
-use days::{
-    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
-    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use etc::answers::{load_answers, record_answer, Answers, Verdict};
+use etc::bench::{bench_day, DayBench};
+use etc::calendar::{latest_unlocked, latest_unlocked_day};
+use etc::cli::{
+    days_or_all, default_year, BenchArgs, Cli, Command, DaySelection, FetchArgs, Part, RunArgs,
+    SubmitArgs,
 };
+use etc::client::{fetch_input, fetch_puzzle, session_token, Client};
+use etc::error::{SolveError, SolveResult};
+use etc::examples::{extract_examples, load_examples, puzzle_path, save_examples, verify_example};
+use etc::history::{
+    self, append_history, find_regressions, load_history, HistoryEntry, HISTORY_PATH,
+};
+use etc::input::{default_input_path, load_input};
+use etc::output::{self, Format, RunRecord};
+use etc::scaffold::scaffold_day;
+use etc::schedule::{run_tasks, Outcome};
 use etc::solution::Solution;
-use hashbrown::HashMap;
+use etc::solver::{find_solver, solvers, PartResult};
+use etc::submit::{
+    append_attempt, attempts_path, load_attempts, parse_reply, Attempt, Attempts, Judgement,
+};
 use itertools::Itertools;
-use std::env;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
 use std::time::Instant;
 
-pub type SolutionPair = (Solution, Solution);
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    // If no arguments are given, try to find the latest day with a non-zero solution pair.
-    if args.len() < 2 {
-        // Check what day of December it is (ie, EST since problems come out at EST) and try that day.
-        // If it's after the 25th, then try the 25th.
-        // If the solution pair is zero, try the previous day until you find a non-zero solution pair.
-        let now = Local::now();
-        let before = Local.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap();
-        let later = Local.with_ymd_and_hms(2024, 12, 25, 0, 0, 0).unwrap();
-        let day = if now > later {
-            25
-        } else if now < before {
-            1
-        } else {
-            now.day()
-        };
+struct DayResult {
+    year: u16,
+    day: u8,
+    parse_ms: f64,
+    parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    fn elapsed_ms(&self) -> f64 {
+        self.parse_ms + self.parts.iter().map(|p| p.elapsed_ms).sum::<f64>()
+    }
+
+    fn is_unsolved(&self) -> bool {
+        self.parts.iter().all(|p| p.solution.is_unsolved())
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let command = cli.command.unwrap_or(Command::Run(cli.run));
 
-        let mut current_day = day;
-        let mut p1;
-        let mut p2;
-        let mut elapsed_ms;
+    let validation = match &command {
+        Command::Run(args) | Command::Check(args) => args.validate(),
+        Command::Bench(args) => args.validate(),
+        _ => Ok(()),
+    };
+    if let Err(message) = validation {
+        Cli::command()
+            .error(ErrorKind::ArgumentConflict, message)
+            .exit();
+    }
 
-        loop {
-            if current_day < day {
+    match command {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Check(args) => check(&args),
+        Command::New { day, year, name } => {
+            new_day(day, year.unwrap_or(latest_unlocked(Utc::now()).0), name)
+        }
+        Command::Fetch(args) => fetch(&args, Utc::now()),
+        Command::Submit(args) => submit(&args, Utc::now()),
+        Command::Examples { days, year, force } => {
+            examples(&days, year.unwrap_or_else(default_year), force)
+        }
+        Command::List => {
+            for year in solvers().chunk_by(|a, b| a.year == b.year) {
+                println!("=== {} ===", year[0].year);
+                for solver in year {
+                    let parts = if solver.parts.is_empty() {
+                        "no solution yet".to_owned()
+                    } else {
+                        let plural = if solver.parts.len() > 1 { "s" } else { "" };
+                        format!("part{} {}", plural, solver.parts.iter().join(", "))
+                    };
+                    println!("Day {:02}: {} ({})", solver.day, solver.name, parts);
+                }
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let text = args.format == Format::Text;
+    let start = Instant::now();
+
+    // If no days are given, try to find the latest solved day.
+    let (results, failures) = if args.days.is_empty() {
+        match find_latest_day(args, Utc::now()) {
+            Some(result) => (vec![result], 0),
+            None => {
+                eprintln!("error: no day has been solved yet");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        solve_days(args, |result| {
+            if text {
+                print_day(result)
+            }
+        })
+    };
+
+    match args.format {
+        Format::Text if args.days.is_empty() => print_day(&results[0]),
+        Format::Text if !results.is_empty() => {
+            let total_runtime = results.iter().map(DayResult::elapsed_ms).sum::<f64>();
+            println!("\nTotal runtime: {:.4} ms", total_runtime);
+            println!(
+                "Average runtime: {:.4} ms",
+                total_runtime / results.len() as f64
+            );
+            if args.parallel {
                 println!(
-                    "Day {} solution is zero, trying previous day {}...",
-                    current_day + 1,
-                    current_day
+                    "Wall-clock time: {:.4} ms",
+                    start.elapsed().as_nanos() as f64 / 1_000_000.0
                 );
-            } else {
+            }
+        }
+        Format::Text => {}
+        Format::Json => print!("{}", output::to_json(&records(&results, &[]))),
+        #[cfg(feature = "serde")]
+        Format::Toml => print!("{}", output::to_toml(&records(&results, &[]))),
+        Format::Csv => print!("{}", output::to_csv(&records(&results, &[]))),
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let run_args = all_if_empty(&args.run);
+    let (warmup, iterations) = (args.warmup as usize, args.iterations as usize);
+    let text = args.run.format == Format::Text;
+    let mut results = Vec::new();
+    let mut failures = 0;
+
+    for day in run_args.days() {
+        let result = find_solver(run_args.year(), day)
+            .and_then(|solver| bench_day(solver, &run_args, warmup, iterations));
+        match result {
+            Ok(Some(result)) => {
+                if text {
+                    print_bench(&result);
+                }
+                results.push(result);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                report_error(day, &err);
+                failures += 1;
+            }
+        }
+    }
+
+    if results.is_empty() {
+        return if failures > 0 {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    let current = history::entries_for(&results, Utc::now());
+    match args.run.format {
+        Format::Text => {
+            println!(
+                "\n=== Sorted Median Runtimes ({} runs, {} warm-up) ===",
+                iterations, warmup
+            );
+            // Print runtimes in order from fastest to slowest, excluding input loading
+            results
+                .iter()
+                .sorted_by(|a, b| a.solve_median().total_cmp(&b.solve_median()))
+                .for_each(|result| {
+                    println!("Day {:02}: {:.4} ms", result.day, result.solve_median());
+                });
+            println!(
+                "Total: {:.4} ms",
+                results.iter().map(DayBench::solve_median).sum::<f64>()
+            );
+        }
+        Format::Json => print!("{}", output::to_json(&current)),
+        #[cfg(feature = "serde")]
+        Format::Toml => print!("{}", output::to_toml(&current)),
+        Format::Csv => print!("{}", history::to_csv(&current)),
+    }
+
+    // Runs on a custom input are not comparable with the recorded ones
+    if args.run.input.is_none() {
+        if let Err(err) = record_history(args, &current, text) {
+            eprintln!("error: could not update {}: {}", HISTORY_PATH, err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Compares a benchmark run against the recorded history, then appends it to the history.
+// Regressions are only reported in the text format.
+fn record_history(args: &BenchArgs, current: &[HistoryEntry], text: bool) -> io::Result<()> {
+    let path = Path::new(HISTORY_PATH);
+    let history = load_history(path)?;
+
+    if text {
+        let regressions = find_regressions(&history, current, args.baseline, args.threshold);
+        if regressions.is_empty() {
+            println!(
+                "\nNo regressions over {}% against the {} run",
+                args.threshold, args.baseline
+            );
+        } else {
+            println!(
+                "\n=== Regressions over {}% against the {} run ===",
+                args.threshold, args.baseline
+            );
+            for regression in &regressions {
                 println!(
-                    "No day argument given. Today is day {}, so trying that day...",
-                    current_day
+                    "{} day {:02} part {}: {:.4} ms -> {:.4} ms (+{:.1}%)",
+                    regression.year,
+                    regression.day,
+                    regression.part,
+                    regression.baseline_ms,
+                    regression.current_ms,
+                    regression.slowdown_percent()
                 );
             }
-            let func = get_day_solver(current_day as u8);
-            let time = Instant::now();
-            let (p1_curr, p2_curr) = func();
-            elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
-            p1 = p1_curr;
-            p2 = p2_curr;
-            if p1 != Solution::from(0) || p2 != Solution::from(0) {
-                break;
-            }
-            current_day -= 1;
         }
+    }
 
-        println!("\n=== Day {:02} ===", current_day);
-        println!("  · Part 1: {}", p1);
-        println!("  · Part 2: {}", p2);
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
-        return;
+    if args.no_save {
+        Ok(())
+    } else {
+        append_history(path, current)
     }
+}
+
+fn new_day(day: u8, year: u16, name: Option<String>) -> ExitCode {
+    // A stub being replaced keeps its title
+    let name = name
+        .or_else(|| {
+            find_solver(year, day)
+                .ok()
+                .map(|solver| solver.name.to_owned())
+        })
+        .unwrap_or_else(|| format!("Day {}", day));
 
-    // Check if -all is given as an argument, and if so, run all days.
-    let days: Vec<u8> = if args[1] == "-all" {
-        (1..=25).collect()
+    match scaffold_day(Path::new("."), year, day, &name) {
+        Ok(changed) => {
+            for path in changed {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: cannot generate day {} of {}: {}", day, year, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Downloads the missing inputs of the selected days, skipping the ones not unlocked at `now`
+fn fetch(args: &FetchArgs, now: DateTime<Utc>) -> ExitCode {
+    let year = args.year.unwrap_or(latest_unlocked(now).0);
+    let Some(unlocked) = latest_unlocked_day(year, now) else {
+        eprintln!("error: the {} event has not started yet", year);
+        return ExitCode::FAILURE;
+    };
+    let (days, locked): (Vec<u8>, Vec<u8>) = if args.days.is_empty() {
+        (vec![unlocked], Vec::new())
     } else {
-        // Otherwise, parse the arguments as days.
-        args[1..]
-            .iter()
-            .map(|x| {
-                x.parse()
-                    .unwrap_or_else(|v| panic!("Not a valid day: {}", v))
-            })
-            .collect()
+        args.days().into_iter().partition(|&day| day <= unlocked)
     };
+    if !locked.is_empty() {
+        eprintln!(
+            "Skipping day{} {}, not unlocked yet",
+            if locked.len() > 1 { "s" } else { "" },
+            locked.iter().join(", ")
+        );
+    }
 
-    let mut runtimes: HashMap<u8, f64> = HashMap::new();
+    let client = match session_token() {
+        Ok(session) => Client::new(&args.server.base_url, session),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
 
+    let mut failures = 0;
     for day in days {
-        let func = get_day_solver(day);
+        let path = default_input_path(Path::new(""), year, day);
+        match fetch_input(&client, year, day, &path) {
+            Ok(true) => println!("Day {:02}: downloaded to {}", day, path.display()),
+            Ok(false) => println!("Day {:02}: already in {}", day, path.display()),
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day, err);
+                failures += 1;
+            }
+        }
+
+        if args.puzzle {
+            let path = puzzle_path(year, day);
+            match fetch_puzzle(&client, year, day, &path) {
+                Ok(()) => println!("Day {:02}: description saved to {}", day, path.display()),
+                Err(err) => {
+                    eprintln!("Day {:02}: {}", day, err);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Extracts example fixtures from the cached descriptions. Without explicit days, the days whose
+// description is not cached are skipped silently.
+fn examples(days: &[DaySelection], year: u16, force: bool) -> ExitCode {
+    let mut failures = 0;
+    for day in days_or_all(days) {
+        let path = puzzle_path(year, day);
+        let html = match std::fs::read_to_string(&path) {
+            Ok(html) => html,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && days.is_empty() => continue,
+            Err(err) => {
+                eprintln!("Day {:02}: could not read {}: {}", day, path.display(), err);
+                failures += 1;
+                continue;
+            }
+        };
 
-        let time = Instant::now();
-        let (p1, p2) = func();
-        let elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
+        let examples = extract_examples(&html);
+        if examples.is_empty() {
+            eprintln!("Day {:02}: no example found in {}", day, path.display());
+            continue;
+        }
+        match save_examples(Path::new("."), year, day, &examples, force) {
+            Ok(written) if written.is_empty() => println!(
+                "Day {:02}: the fixtures already exist, use --force to replace them",
+                day
+            ),
+            Ok(written) => written
+                .iter()
+                .for_each(|path| println!("Wrote {}", path.display())),
+            Err(err) => {
+                eprintln!("Day {:02}: could not write the fixtures: {}", day, err);
+                failures += 1;
+                continue;
+            }
+        }
 
-        if p1 == Solution::from(0) && p2 == Solution::from(0) {
+        // Try the fixtures right away on the parts solved so far
+        let Ok(solver) = find_solver(year, day) else {
             continue;
+        };
+        let fixtures = load_examples(Path::new("."), year, day).unwrap_or_default();
+        for (path, example) in fixtures {
+            match verify_example(solver, &example) {
+                Ok(results) => {
+                    for (part, solution, verdict) in results {
+                        let verdict = match verdict {
+                            Verdict::Pass => "pass".to_owned(),
+                            Verdict::Fail { expected } => format!("FAIL, expected {}", expected),
+                            _ => "unknown".to_owned(),
+                        };
+                        let label = format!("{} part {}", path.display(), part);
+                        let detail = format!("[{}]", verdict);
+                        println!("{}", output::text_line(&label, &solution, &detail));
+                    }
+                }
+                Err(err) => println!("  · {}: {}", path.display(), err),
+            }
         }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
 
-        println!("\n=== Day {:02} ===", day);
-        println!("  · Part 1: {}", p1);
-        println!("  · Part 2: {}", p2);
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
+fn submit(args: &SubmitArgs, now: DateTime<Utc>) -> ExitCode {
+    match submit_answer(args, now) {
+        Ok(Judgement::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-        runtimes.insert(day, elapsed_ms);
+// Solves a part and submits its answer, unless it is known to be wrong or an earlier attempt is
+// still cooling down. Every submission is recorded, and a correct answer is added to the answers.
+fn submit_answer(args: &SubmitArgs, now: DateTime<Utc>) -> Result<Judgement, String> {
+    let (year, day, part) = (args.year.unwrap_or_else(default_year), args.day, args.part);
+    let run_args = RunArgs {
+        year: Some(year),
+        part: Some(part),
+        ..RunArgs::default()
+    };
+    let result = solve_one(day, &run_args).map_err(|err| format!("day {}: {}", day, err))?;
+    let solution = result
+        .parts
+        .into_iter()
+        .map(|result| result.solution)
+        .find(|solution| !solution.is_unsolved())
+        .ok_or_else(|| format!("part {} of day {} has no solution yet", part, day))?;
+    println!("Day {:02} part {}: {}", day, part, solution);
+    if solution.to_string().contains('\n') {
+        return Err(
+            "a multi-line answer cannot be submitted, read its letters with etc::ocr first"
+                .to_owned(),
+        );
     }
 
-    let total_runtime = runtimes.values().sum::<f64>();
-    println!("\nTotal runtime: {:.4} ms", total_runtime);
-    println!(
-        "Average runtime: {:.4} ms",
-        total_runtime / runtimes.len() as f64
-    );
+    let answers = load_answers(year, day).map_err(|err| err.to_string())?;
+    let path = attempts_path(year, day);
+    let attempts = load_attempts(&path).map_err(|err| err.to_string())?;
+    match verify(&answers, &attempts, part, &solution) {
+        Verdict::Pass => return Err("this answer was already accepted".to_owned()),
+        Verdict::Fail { expected } => {
+            return Err(format!(
+                "the part is already solved, its answer is {}",
+                expected
+            ))
+        }
+        Verdict::Rejected { reason } => return Err(format!("not submitting, {}", reason)),
+        Verdict::Unknown => {}
+    }
+    if let Some(retry_at) = attempts.cooldown(now) {
+        return Err(format!(
+            "the previous answer was submitted too recently, wait {}s before submitting again",
+            (retry_at - now).num_seconds() + 1
+        ));
+    }
 
-    println!("\n=== Sorted Runtimes ===");
-    // Print runtimes in order from fastest to slowest
-    runtimes
-        .iter()
-        .sorted_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
-        .for_each(|(day, runtime)| {
-            println!("Day {:02}: {:.4} ms", day, runtime);
+    let session = session_token().map_err(|err| err.to_string())?;
+    let client = Client::new(&args.server.base_url, session);
+    let html = client
+        .answer(year, day, part, &solution.to_string())
+        .map_err(|err| err.to_string())?;
+    let reply = parse_reply(&html)?;
+
+    let attempt = Attempt::new(now, part, solution.to_string(), &reply);
+    append_attempt(&path, &attempt).map_err(|err| {
+        format!(
+            "could not record the attempt in {}: {}",
+            path.display(),
+            err
+        )
+    })?;
+
+    match reply.judgement {
+        Judgement::Correct => {
+            println!("That's the right answer!");
+            record_answer(year, day, part, &attempt.answer)
+                .map_err(|err| format!("could not record the answer: {}", err))?;
+        }
+        Judgement::AlreadySolved => println!("The part is already solved"),
+        Judgement::TooSoon => {
+            println!("Not judged, the previous answer was submitted too recently")
+        }
+        judgement => println!("That's not the right answer ({})", judgement),
+    }
+    if let Some(wait) = reply.wait {
+        println!("Wait {}s before submitting another answer", wait.as_secs());
+    }
+    Ok(reply.judgement)
+}
+
+// Compares an answer against the recorded one, or else against the earlier submissions
+fn verify(answers: &Answers, attempts: &Attempts, part: Part, solution: &Solution) -> Verdict {
+    match answers.verify(part, solution) {
+        Verdict::Unknown => attempts.verify(part, solution),
+        verdict => verdict,
+    }
+}
+
+fn check(args: &RunArgs) -> ExitCode {
+    let text = args.format == Format::Text;
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    let mut statuses = Vec::new();
+
+    let (results, failures) = solve_days(&all_if_empty(args), |result| {
+        let answers = load_answers(result.year, result.day).unwrap_or_else(|err| {
+            eprintln!(
+                "Day {:02}: could not read the recorded answers: {}",
+                result.day, err
+            );
+            failed += 1;
+            Answers::default()
         });
+        let attempts =
+            load_attempts(&attempts_path(result.year, result.day)).unwrap_or_else(|err| {
+                eprintln!(
+                    "Day {:02}: could not read the submitted answers: {}",
+                    result.day, err
+                );
+                failed += 1;
+                Attempts::default()
+            });
+
+        if text {
+            println!("\n=== Day {:02} ===", result.day);
+        }
+        for part in &result.parts {
+            let (status, verdict) = match verify(&answers, &attempts, part.part, &part.solution) {
+                Verdict::Pass => {
+                    passed += 1;
+                    ("pass", "pass".to_owned())
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    ("fail", format!("FAIL, expected {}", expected))
+                }
+                Verdict::Rejected { reason } => {
+                    failed += 1;
+                    ("fail", format!("FAIL, {}", reason))
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    ("unknown", "unknown".to_owned())
+                }
+            };
+            if text {
+                let label = format!("Part {}", part.part);
+                let detail = format!("[{}]", verdict);
+                println!("{}", output::text_line(&label, &part.solution, &detail));
+            }
+            statuses.push(status);
+        }
+    });
+
+    failed += failures;
+    match args.format {
+        Format::Text => println!(
+            "\nChecked answers: {} passed, {} failed, {} unknown",
+            passed, failed, unknown
+        ),
+        Format::Json => print!("{}", output::to_json(&records(&results, &statuses))),
+        #[cfg(feature = "serde")]
+        Format::Toml => print!("{}", output::to_toml(&records(&results, &statuses))),
+        Format::Csv => print!("{}", output::to_csv(&records(&results, &statuses))),
+    }
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Flattens day results into one record per part, with the matching status if there are any
+fn records(results: &[DayResult], statuses: &[&str]) -> Vec<RunRecord> {
+    results
+        .iter()
+        .flat_map(|result| result.parts.iter().map(move |part| (result, part)))
+        .enumerate()
+        .map(|(i, (result, part))| RunRecord {
+            year: result.year,
+            day: result.day,
+            part: part.part,
+            answer: part.solution.clone(),
+            kind: part.solution.kind().to_owned(),
+            parse_ms: result.parse_ms,
+            elapsed_ms: part.elapsed_ms,
+            status: statuses.get(i).map(|status| status.to_string()),
+        })
+        .collect()
+}
+
+// Without any days, commands that summarize several days select all of them
+fn all_if_empty(args: &RunArgs) -> RunArgs {
+    if args.days.is_empty() {
+        RunArgs {
+            days: vec![DaySelection::all()],
+            ..args.clone()
+        }
+    } else {
+        args.clone()
+    }
+}
+
+// Try the latest day of the selected year that has been unlocked. Puzzles unlock at midnight
+// US Eastern Time whatever the timezone of this machine, and the 25th is the last day.
+// If the day is not solved yet, try the previous day until you find a solved one.
+fn find_latest_day(args: &RunArgs, now: DateTime<Utc>) -> Option<DayResult> {
+    let year = args.year();
+    let Some(day) = latest_unlocked_day(year, now) else {
+        eprintln!(
+            "No day argument given, and the {} event has not started yet",
+            year
+        );
+        return None;
+    };
+
+    let mut current_day = day;
+    if latest_unlocked(now).0 > year {
+        eprintln!(
+            "No day argument given. The {} event is over, so trying day {}...",
+            year, day
+        );
+    } else {
+        eprintln!(
+            "No day argument given. Today is day {} of {}, so trying that day...",
+            day, year
+        );
+    }
+
+    loop {
+        match solve_one(current_day, args) {
+            Ok(result) if !result.is_unsolved() => return Some(result),
+            Ok(_) => eprint!("Day {} is not solved yet", current_day),
+            Err(err) => eprint!("Day {} failed ({})", current_day, err),
+        }
+        if current_day == 1 {
+            eprintln!();
+            return None;
+        }
+        current_day -= 1;
+        eprintln!(", trying previous day {}...", current_day);
+    }
+}
+
+// Solves every selected day, skipping unsolved ones and reporting each one in order as soon as it
+// and the days before it are done. Days that fail or time out are reported and skipped too, and
+// counted in the returned number of failures.
+fn solve_days(args: &RunArgs, mut report: impl FnMut(&DayResult)) -> (Vec<DayResult>, usize) {
+    let mut failures = 0;
+    let mut results = Vec::new();
+    solve_each(&args.days(), args, |day, result| match result {
+        Ok(result) if result.is_unsolved() => {}
+        Ok(result) => {
+            report(&result);
+            results.push(result);
+        }
+        Err(err) => {
+            report_error(day, &err);
+            failures += 1;
+        }
+    });
+    (results, failures)
+}
+
+fn solve_one(day: u8, args: &RunArgs) -> Result<DayResult, SolveError> {
+    let mut result = None;
+    solve_each(&[day], args, |_, r| result = Some(r));
+    result.expect("every day is reported")
+}
+
+// Solves the days one after another or in parallel, giving up on the ones that exceed the timeout
+fn solve_each(
+    days: &[u8],
+    args: &RunArgs,
+    mut report: impl FnMut(u8, Result<DayResult, SolveError>),
+) {
+    let task_args = args.clone();
+    let task = move |day| solve_day(day, &task_args);
+    run_tasks(days, args.parallel, args.timeout, task, |&day, outcome| {
+        let result = match outcome {
+            Outcome::Done(result) => result,
+            Outcome::TimedOut => Err(SolveError::TimedOut(
+                args.timeout.expect("only days with a timeout time out"),
+            )),
+            Outcome::Panicked => Err(SolveError::Panicked),
+        };
+        report(day, result);
+    });
+}
+
+// Solves the selected parts of a day, timing the parsing and each part on its own
+fn solve_day(day: u8, args: &RunArgs) -> Result<DayResult, SolveError> {
+    let year = args.year();
+    let solver = find_solver(year, day)?;
+    let parts = args.parts();
+
+    // Days without a solution are skipped without an input, and otherwise still solved to
+    // check that no part answers without being listed
+    let input = match load_input(year, day, args.input.as_deref()) {
+        Err(_) if !parts.iter().any(|&part| solver.implements(part)) => {
+            return Ok(DayResult {
+                year,
+                day,
+                parse_ms: 0.0,
+                parts: Vec::new(),
+            })
+        }
+        input => input?,
+    };
+    let solved = solver.solve(&input, &parts)?;
+    Ok(DayResult {
+        year,
+        day,
+        parse_ms: solved.parse_ms,
+        parts: solved.parts,
+    })
+}
+
+fn report_error(day: u8, err: &SolveError) {
+    eprintln!("Day {:02}: {}", day, err);
+}
+
+fn print_bench(result: &DayBench) {
+    println!("\n=== Day {:02} ===", result.day);
+    println!(
+        "  {:<8} {:>10} {:>10} {:>10} {:>10}",
+        "", "min", "median", "mean", "stddev"
+    );
+    let rows = [
+        ("Load".to_owned(), &result.load),
+        ("Parse".to_owned(), &result.parse),
+    ]
+    .into_iter()
+    .chain(
+        result
+            .parts
+            .iter()
+            .map(|(part, stats)| (format!("Part {}", part), stats)),
+    );
+    for (name, stats) in rows {
+        println!(
+            "  · {:<6} {:>10.4} {:>10.4} {:>10.4} {:>10.4} ms",
+            name, stats.min, stats.median, stats.mean, stats.stddev
+        );
+    }
 }
 
-fn get_day_solver(day: u8) -> fn() -> SolutionPair {
-    match day {
-        1 => day01::solve,
-        2 => day02::solve,
-        3 => day03::solve,
-        4 => day04::solve,
-        5 => day05::solve,
-        6 => day06::solve,
-        7 => day07::solve,
-        8 => day08::solve,
-        9 => day09::solve,
-        10 => day10::solve,
-        11 => day11::solve,
-        12 => day12::solve,
-        13 => day13::solve,
-        14 => day14::solve,
-        15 => day15::solve,
-        16 => day16::solve,
-        17 => day17::solve,
-        18 => day18::solve,
-        19 => day19::solve,
-        20 => day20::solve,
-        21 => day21::solve,
-        22 => day22::solve,
-        23 => day23::solve,
-        24 => day24::solve,
-        25 => day25::solve,
-        _ => unimplemented!(),
+fn print_day(result: &DayResult) {
+    println!("\n=== Day {:02} ===", result.day);
+    println!("  · Parse: {:.4} ms", result.parse_ms);
+    for part in &result.parts {
+        let label = format!("Part {}", part.part);
+        let detail = format!("({:.4} ms)", part.elapsed_ms);
+        println!("{}", output::text_line(&label, &part.solution, &detail));
     }
+    println!("  · Elapsed: {:.4} ms", result.elapsed_ms());
 }
@@ -0,0 +1,100 @@
+use crate::etc::parse::{lines, parse_all, unsigned};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use winnow::ascii::space1;
+use winnow::combinator::separated_pair;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    const NAME: &'static str = "Historian Hysteria";
+
+    type Input<'a> = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_lists(input)
+    }
+
+    fn part1((left_list, right_list): &Self::Input<'_>) -> SolveResult {
+        let sol1 = total_distance(left_list, right_list);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2((left_list, right_list): &Self::Input<'_>) -> SolveResult {
+        let sol2 = total_similarity_score(left_list, right_list);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day01>() }
+
+// two columns of numbers separated by spaces
+fn parse_lists(input: &str) -> Result<(Vec<u32>, Vec<u32>), SolveError> {
+    let pairs: Vec<(u32, u32)> =
+        parse_all(input, lines(separated_pair(unsigned, space1, unsigned)))?;
+
+    Ok(pairs.into_iter().unzip())
+}
+
+fn total_distance(left_list: &[u32], right_list: &[u32]) -> u32 {
+    let mut left_list = left_list.to_vec();
+    let mut right_list = right_list.to_vec();
+
+    left_list.sort_unstable();
+    right_list.sort_unstable();
+
+    left_list
+        .iter()
+        .zip(right_list.iter())
+        .map(|(left, right)| (*left as i32 - *right as i32).unsigned_abs())
+        .sum()
+}
+
+fn total_similarity_score(left_list: &[u32], right_list: &[u32]) -> u32 {
+    use std::collections::HashMap;
+    let mut right_count: HashMap<u32, u32> = HashMap::new();
+    for &num in right_list {
+        *right_count.entry(num).or_insert(0) += 1;
+    }
+
+    left_list
+        .iter()
+        .map(|&num| num * right_count.get(&num).unwrap_or(&0))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_total_distance() {
+        let (left_list, right_list) = parse_lists(&fixture(2024, 1, 0).input).unwrap();
+        assert_eq!(total_distance(&left_list, &right_list), 11);
+    }
+
+    #[test]
+    fn test_total_similarity_score() {
+        let (left_list, right_list) = parse_lists(&fixture(2024, 1, 0).input).unwrap();
+        assert_eq!(total_similarity_score(&left_list, &right_list), 31);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(matches!(
+            parse_lists("3   4\n4   x"),
+            Err(SolveError::Parse {
+                line: 2,
+                column: 5,
+                ..
+            })
+        ));
+    }
+}
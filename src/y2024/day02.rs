@@ -0,0 +1,94 @@
+use crate::etc::parse::{lines, parse_all, signed, spaced};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+    const NAME: &'static str = "Red-Nosed Reports";
+
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_reports(input)
+    }
+
+    fn part1(reports: &Self::Input<'_>) -> SolveResult {
+        let sol1 = count_safe_reports(reports);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(reports: &Self::Input<'_>) -> SolveResult {
+        let sol2 = count_safe_reports_with_dampener(reports);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day02>() }
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<i32>>, SolveError> {
+    parse_all(input, lines(spaced(signed)))
+}
+
+fn count_safe_reports(reports: &[Vec<i32>]) -> u32 {
+    reports
+        .iter()
+        .filter(|numbers| {
+            // println!("{:?} safe: {}", numbers, is_safe(numbers));
+            is_safe(numbers)
+        })
+        .count() as u32
+}
+
+fn is_safe(numbers: &[i32]) -> bool {
+    let differences: Vec<i32> = numbers.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+    differences.iter().all(|&diff| (1..=3).contains(&diff)) // All increasing
+        || differences.iter().all(|&diff| (-3..=-1).contains(&diff))
+}
+
+fn count_safe_reports_with_dampener(reports: &[Vec<i32>]) -> u32 {
+    reports
+        .iter()
+        .filter(|numbers| {
+            // Base case: If the sequence is safe, return true
+            if is_safe(numbers) {
+                return true;
+            }
+
+            // Try removing one number at a time and check for safety
+            for i in 0..numbers.len() {
+                let mut reduced_numbers = numbers.to_vec();
+                reduced_numbers.remove(i);
+                if is_safe(&reduced_numbers) {
+                    return true;
+                }
+            }
+
+            false
+        })
+        .count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_count_safe_reports() {
+        let reports = parse_reports(&fixture(2024, 2, 0).input).unwrap();
+        assert_eq!(count_safe_reports(&reports), 2);
+    }
+
+    #[test]
+    fn test_count_safe_reports_with_dampener() {
+        let reports = parse_reports(&fixture(2024, 2, 0).input).unwrap();
+        assert_eq!(count_safe_reports_with_dampener(&reports), 4);
+    }
+}
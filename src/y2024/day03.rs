@@ -1,22 +1,39 @@
-use crate::{Solution, SolutionPair};
-use chrono::format::parse;
-use itertools::Itertools;
-use std::fs::read_to_string;
-use winnow::combinator::{alt, delimited, opt, preceded, repeat, repeat_till, separated_pair};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use winnow::combinator::{alt, delimited, repeat, repeat_till, separated_pair};
 use winnow::prelude::*;
 use winnow::seq;
-use winnow::stream::Stream;
-use winnow::token::{any, literal, take_until, take_while};
+use winnow::token::{any, take_while};
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day03.txt").expect("Day 3 input file should be present");
-    let sol1 = parse_and_sum(&input);
-    let sol2 = parse_and_sum_with_ignore(&input);
+pub struct Day03;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Solver for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+    const NAME: &'static str = "Mull It Over";
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> SolveResult {
+        let sol1 = parse_and_sum(input);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> SolveResult {
+        let sol2 = parse_and_sum_with_ignore(input);
+
+        Ok(Solution::from(sol2))
+    }
 }
 
+inventory::submit! { DaySolver::new::<Day03>() }
+
 fn parse_and_sum(input: &str) -> u32 {
     let mut input = input;
 
@@ -29,7 +46,7 @@ fn parse_and_sum(input: &str) -> u32 {
         .unwrap_or(0)
 }
 
-fn parse_mul(input: &mut &str) -> PResult<Option<u32>> {
+fn parse_mul(input: &mut &str) -> ModalResult<Option<u32>> {
     repeat_till(
         0..,
         any,
@@ -40,7 +57,7 @@ fn parse_mul(input: &mut &str) -> PResult<Option<u32>> {
     .parse_next(input)
 }
 
-fn parse_number(input: &mut &str) -> PResult<u32> {
+fn parse_number(input: &mut &str) -> ModalResult<u32> {
     take_while(1..=3, |c: char| c.is_ascii_digit())
         .try_map(|digits: &str| digits.parse::<u32>())
         .parse_next(input)
@@ -64,7 +81,7 @@ fn parse_and_sum_with_ignore(input: &str) -> u32 {
         .unwrap_or(0)
 }
 
-fn parse_skip_dont_to_do(input: &mut &str) -> PResult<Option<u32>> {
+fn parse_skip_dont_to_do(input: &mut &str) -> ModalResult<Option<u32>> {
     alt((
         // don't do block
         seq!(
@@ -85,27 +102,16 @@ fn parse_skip_dont_to_do(input: &mut &str) -> PResult<Option<u32>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn test_input() -> &'static str {
-        r#"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"#
-    }
-
-    fn test_input_2() -> &'static str {
-        r#"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"#
-    }
-
-    fn test_input_3() -> &'static str {
-        r#"xmul(2,4)&mul(3,7)!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"#
-    }
+    use crate::etc::examples::fixture;
 
     #[test]
     fn test_parse_and_sum() {
-        assert_eq!(parse_and_sum(test_input()), 161);
+        assert_eq!(parse_and_sum(&fixture(2024, 3, 0).input), 161);
     }
 
     #[test]
     fn test_parse_and_sum_with_ignore() {
-        assert_eq!(parse_and_sum_with_ignore(test_input_2()), 48);
-        assert_eq!(parse_and_sum_with_ignore(test_input_3()), 69);
+        assert_eq!(parse_and_sum_with_ignore(&fixture(2024, 3, 1).input), 48);
+        assert_eq!(parse_and_sum_with_ignore(&fixture(2024, 3, 2).input), 69);
     }
 }
@@ -0,0 +1,107 @@
+use crate::etc::geometry::{Point, Vec2};
+use crate::etc::grid::{parse_grid, GridExt};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use grid::Grid;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+    const NAME: &'static str = "Ceres Search";
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> SolveResult {
+        let sol1 = count_word_in_word_search(grid);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> SolveResult {
+        let sol2 = count_cross_word_in_grid(grid);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day04>() }
+
+static WORD_TO_FIND: &str = "XMAS";
+
+// XMAS can show up vertically, horizontally, or diagonally
+fn count_word_in_word_search(grid: &Grid<char>) -> u32 {
+    let word: Vec<char> = WORD_TO_FIND.chars().collect();
+
+    let mut count = 0;
+
+    // Check each starting point in the grid
+    for (start, _) in grid.indexed_iter() {
+        // Check in each direction
+        for direction in Vec2::ALL_AROUND {
+            if matches_word(grid, start.into(), &word, direction) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+// Function to check if the word matches starting at `start` in the given direction
+fn matches_word(grid: &Grid<char>, start: Point, word: &[char], direction: Vec2) -> bool {
+    word.iter().enumerate().all(|(i, char_to_match)| {
+        // Out of bounds positions match nothing
+        start
+            .checked_add(direction * i as isize)
+            .and_then(|position| grid.at(position))
+            == Some(char_to_match)
+    })
+}
+
+fn count_cross_word_in_grid(grid: &Grid<char>) -> u32 {
+    let mut count = 0;
+
+    for (position, &char) in grid.indexed_iter() {
+        if char == 'A' {
+            // Collect the four corners around the "A", clockwise from the top right
+            let corners: String = Vec2::DIAGONAL
+                .iter()
+                .filter_map(|&offset| Point::from(position).checked_add(offset))
+                .filter_map(|corner| grid.at(corner))
+                .collect();
+
+            // Check if these form any rotation of [M, M, S, S]
+            if ["MMSS", "MSSM", "SSMM", "SMMS"].contains(&corners.as_str()) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_count_word_in_word_search() {
+        let grid = Day04::parse(&fixture(2024, 4, 0).input).unwrap();
+        assert_eq!(count_word_in_word_search(&grid), 18);
+    }
+
+    #[test]
+    fn test_count_cross_word_in_grid() {
+        let grid = Day04::parse(&fixture(2024, 4, 0).input).unwrap();
+        assert_eq!(count_cross_word_in_grid(&grid), 9);
+    }
+}
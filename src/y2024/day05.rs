@@ -0,0 +1,158 @@
+use crate::etc::parse::{blank_line, comma_separated, lines, parse_all, unsigned};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use hashbrown::{HashMap, HashSet};
+use itertools::Itertools;
+use winnow::combinator::separated_pair;
+use winnow::prelude::*;
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+    const NAME: &'static str = "Print Queue";
+
+    type Input<'a> = (Rules, Vec<Vec<u32>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_manual(input)
+    }
+
+    fn part1((rules, updates): &Self::Input<'_>) -> SolveResult {
+        let sol1 = sum_updates(rules, updates, true);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2((rules, updates): &Self::Input<'_>) -> SolveResult {
+        let sol2 = sum_updates(rules, updates, false);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day05>() }
+
+// for each page, the pages that must come before it
+type Rules = HashMap<u32, HashSet<u32>>;
+
+// a page with its text in the input
+type Located<'a> = (u32, &'a str);
+
+// rules `a|b`, a blank line, then comma-separated updates
+fn parse_manual(input: &str) -> Result<(Rules, Vec<Vec<u32>>), SolveError> {
+    let rule = separated_pair(unsigned, '|', unsigned);
+    // pages keep their text to locate errors found once all the rules are known
+    let page = unsigned.with_taken();
+    let (mappings, lists): (Vec<(u32, u32)>, Vec<Vec<Located>>) = parse_all(
+        input,
+        separated_pair(lines(rule), blank_line, lines(comma_separated(page))),
+    )?;
+
+    let mut rules: Rules = HashMap::new();
+    for (a, b) in mappings {
+        rules.entry(b).or_default().insert(a);
+        rules.entry(a).or_default();
+    }
+
+    // each page must be placed by a rule, and only once
+    let mut updates = Vec::with_capacity(lists.len());
+    for list in lists {
+        let mut seen = HashSet::new();
+        for &(page, text) in &list {
+            if !rules.contains_key(&page) {
+                return Err(SolveError::at(
+                    input,
+                    text,
+                    format!("page {} is in no rule", page),
+                ));
+            }
+            if !seen.insert(page) {
+                return Err(SolveError::at(
+                    input,
+                    text,
+                    format!("page {} is repeated", page),
+                ));
+            }
+        }
+        updates.push(list.into_iter().map(|(page, _)| page).collect());
+    }
+
+    Ok((rules, updates))
+}
+
+// get middle value (assume all lists are odd length) of correctly sorted lists
+fn sum_updates(rules: &Rules, updates: &[Vec<u32>], correctly_ordered: bool) -> u32 {
+    updates
+        .iter()
+        .map(|nums| {
+            let sorted_nums = sort_pages(nums, rules);
+            if correctly_ordered == (*nums == sorted_nums) {
+                // part 1 and part 2 are same, except checking opposite conditions
+                sorted_nums[nums.len() / 2]
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn sort_pages(nums: &[u32], rules: &Rules) -> Vec<u32> {
+    let nums_set: HashSet<u32> = nums.iter().copied().collect();
+
+    rules
+        .iter()
+        .filter_map(|(num, before)| {
+            if nums_set.contains(num) {
+                Some((*num, before.intersection(&nums_set).count()))
+            } else {
+                None
+            }
+        })
+        .sorted_by_key(|(_, before_nums)| *before_nums)
+        .map(|(num, _)| num)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_sum_updates() {
+        let (rules, updates) = parse_manual(&fixture(2024, 5, 0).input).unwrap();
+        assert_eq!(sum_updates(&rules, &updates, true), 143);
+    }
+
+    #[test]
+    fn test_parse_manual_error() {
+        assert!(matches!(
+            parse_manual("47|53\n\n75,4x,61"),
+            Err(SolveError::Parse {
+                line: 3,
+                column: 5,
+                ..
+            })
+        ));
+        assert!(parse_manual("47|53").is_err());
+        assert!(matches!(
+            parse_manual("1|2\n\n3,4,5"),
+            Err(SolveError::Parse {
+                line: 3,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_manual("1|2\n\n1,2\n1,1,1"),
+            Err(SolveError::Parse {
+                line: 4,
+                column: 3,
+                ..
+            })
+        ));
+    }
+}
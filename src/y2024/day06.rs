@@ -0,0 +1,156 @@
+use crate::etc::geometry::{Direction, Point};
+use crate::etc::grid::{find, parse_grid, GridExt};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use grid::*;
+use hashbrown::HashSet;
+use rayon::prelude::*;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+    const NAME: &'static str = "Guard Gallivant";
+
+    // the map and the starting position of the guard
+    type Input<'a> = (Grid<char>, Point);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        let grid = parse_grid(input)?;
+        let guard_start = find_guard(&grid)?;
+        Ok((grid, guard_start))
+    }
+
+    fn part1((grid, guard_start): &Self::Input<'_>) -> SolveResult {
+        let sol1: u32 = get_distinct_visited(grid, *guard_start).len() as u32;
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2((grid, guard_start): &Self::Input<'_>) -> SolveResult {
+        let visited_spaces: HashSet<Point> = get_distinct_visited(grid, *guard_start);
+        let sol2: u32 = count_looping_obstructions(grid, *guard_start, visited_spaces);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day06>() }
+
+fn find_guard(grid: &Grid<char>) -> Result<Point, SolveError> {
+    find(grid, &'^').ok_or_else(|| SolveError::parse(1, 1, "the map has no guard `^`"))
+}
+
+fn get_distinct_visited(grid: &Grid<char>, guard_start: Point) -> HashSet<Point> {
+    let mut visited_spaces: HashSet<Point> = HashSet::new();
+
+    visited_spaces.insert(guard_start);
+
+    let mut guard: (Point, Direction) = (guard_start, Direction::North);
+
+    loop {
+        let (current_pos, current_dir) = guard;
+
+        let next_pos = current_pos.step(current_dir);
+
+        match next_pos.and_then(|next_pos| Some((next_pos, grid.at(next_pos)?))) {
+            Some((_, &'#')) => {
+                // turn right
+                guard = (current_pos, current_dir.turn_right());
+            }
+            Some((next_pos, _)) => {
+                // move forward
+                guard = (next_pos, current_dir);
+                visited_spaces.insert(next_pos);
+            }
+            None => {
+                // out of bounds
+                break;
+            }
+        }
+    }
+
+    visited_spaces
+}
+
+// count the number of looping paths if we place one obstruction
+// TODO: SLOW SLOW SLOW, 300ms EWWW
+fn count_looping_obstructions(
+    grid: &Grid<char>,
+    guard_start: Point,
+    mut visited_spaces: HashSet<Point>,
+) -> u32 {
+    // We use visited_spaces from part 1 to optimize, only try placing obstructions from visited_spaces
+
+    let guard: (Point, Direction) = (guard_start, Direction::North);
+
+    visited_spaces.remove(&guard.0);
+
+    visited_spaces
+        .par_iter()
+        .filter(|&obstruction| check_looping_path(*obstruction, guard, grid))
+        .count() as u32
+}
+
+fn check_looping_path(obstruction: Point, guard: (Point, Direction), grid: &Grid<char>) -> bool {
+    let mut grid = grid.clone(); // Clone the grid to avoid modifying the original
+                                 // Place the obstruction in the grid
+    if let Some(cell) = grid.at_mut(obstruction) {
+        *cell = '#';
+    }
+
+    let mut visited_states: HashSet<(Point, Direction)> = HashSet::new();
+    let mut current_guard = guard;
+
+    loop {
+        let (current_pos, current_dir) = current_guard;
+
+        // If this state was visited before, we have a loop
+        if !visited_states.insert((current_pos, current_dir)) {
+            return true;
+        }
+
+        // Calculate the next position
+        let next_pos = current_pos.step(current_dir);
+
+        match next_pos.and_then(|next_pos| Some((next_pos, grid.at(next_pos)?))) {
+            Some((_, &'#')) => {
+                // Turn right on obstruction
+                current_guard = (current_pos, current_dir.turn_right());
+            }
+            Some((next_pos, _)) => {
+                // Move forward if the path is clear
+                current_guard = (next_pos, current_dir);
+            }
+            None => {
+                // Out of bounds, terminate
+                return false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_count_distinct_visited() {
+        let (grid, guard_start) = Day06::parse(&fixture(2024, 6, 0).input).unwrap();
+        assert_eq!(get_distinct_visited(&grid, guard_start).len(), 41);
+    }
+
+    #[test]
+    fn test_count_looping_obstructions() {
+        let (grid, guard_start) = Day06::parse(&fixture(2024, 6, 0).input).unwrap();
+        let visited_spaces = get_distinct_visited(&grid, guard_start);
+        assert_eq!(
+            count_looping_obstructions(&grid, guard_start, visited_spaces),
+            6
+        );
+    }
+}
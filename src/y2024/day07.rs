@@ -0,0 +1,120 @@
+use crate::etc::parse::{key_values, lines, parse_all, unsigned};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+    const NAME: &'static str = "Bridge Repair";
+
+    type Input<'a> = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_all(input, lines(key_values(unsigned, unsigned)))
+    }
+
+    fn part1(equations: &Self::Input<'_>) -> SolveResult {
+        let sol1 = total_calibration_result(equations, false);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(equations: &Self::Input<'_>) -> SolveResult {
+        let sol2 = total_calibration_result(equations, true);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day07>() }
+
+// left to right, add or multiply, get total of all correct equations
+fn total_calibration_result(equations: &[(u64, Vec<u64>)], with_concat: bool) -> u64 {
+    // brute force will certainly come back to bite me in part 2, there has to be some sort of logic
+    equations
+        .iter()
+        .filter(|(target, numbers)| can_match_target(numbers, *target, 1, numbers[0], with_concat))
+        .map(|(target, _)| target)
+        .sum()
+}
+
+// recursive
+fn can_match_target(
+    numbers: &[u64],
+    target: u64,
+    index: usize,
+    current_value: u64,
+    with_concat: bool,
+) -> bool {
+    // Base case
+    if index == numbers.len() {
+        return current_value == target;
+    }
+
+    let next_number = numbers[index];
+
+    if can_match_target(
+        numbers,
+        target,
+        index + 1,
+        current_value + next_number,
+        with_concat,
+    ) {
+        return true;
+    }
+
+    if current_value <= target / next_number
+        && can_match_target(
+            numbers,
+            target,
+            index + 1,
+            current_value * next_number,
+            with_concat,
+        )
+    {
+        return true;
+    }
+
+    if with_concat {
+        // A concatenation that overflows is certainly above the target
+        let concatenated_value = format!("{}{}", current_value, next_number).parse::<u64>();
+        if let Ok(concatenated_value) = concatenated_value {
+            if concatenated_value <= target
+                && can_match_target(numbers, target, index + 1, concatenated_value, with_concat)
+            {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_total_calibration_result() {
+        let equations = Day07::parse(&fixture(2024, 7, 0).input).unwrap();
+        assert_eq!(total_calibration_result(&equations, false), 3749);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            Day07::parse("190: 10 19\n3267: 81 40 27\n190: 10 1x"),
+            Err(SolveError::Parse {
+                line: 3,
+                column: 10,
+                ..
+            })
+        ));
+        assert!(Day07::parse("190 10 19").is_err());
+        assert!(Day07::parse("190:").is_err());
+    }
+}
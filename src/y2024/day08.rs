@@ -0,0 +1,151 @@
+use crate::etc::geometry::Point;
+use crate::etc::grid::{parse_grid, GridExt};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use grid::*;
+use hashbrown::{HashMap, HashSet};
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+    const NAME: &'static str = "Resonant Collinearity";
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> SolveResult {
+        let sol1: u32 = count_antinodes(grid);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> SolveResult {
+        let sol2: u32 = count_resonant_antinodes(grid);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day08>() }
+
+// an antinode is a unique position within the grid bounds
+// relative to two frequencies such that the distance to one of the frequencies
+// is twice the distance between the two frequences
+// ie for f=(x, y), f'=(x', y'), a=(x+2dx, y+2dy) where (dx, dy)=(x-x', y-y')
+// for each pair of frequencies there are two possible antinodes
+fn count_antinodes(grid: &Grid<char>) -> u32 {
+    let mut antennas: HashMap<char, HashSet<Point>> = HashMap::new();
+
+    grid.indexed_iter()
+        .filter(|(_, char)| char.is_alphanumeric())
+        .for_each(|(position, &char)| {
+            antennas.entry(char).or_default().insert(position.into());
+        });
+
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    for (_frequency, positions) in &antennas {
+        for &pos1 in positions {
+            for &pos2 in positions {
+                if pos1 == pos2 {
+                    continue;
+                }
+
+                let antinode = pos1.checked_add((pos2 - pos1) * 2);
+
+                if let Some(antinode) = antinode.filter(|&antinode| grid.contains(antinode)) {
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+    }
+
+    antinodes.len() as u32
+}
+
+fn count_resonant_antinodes(grid: &Grid<char>) -> u32 {
+    let mut antennas: HashMap<char, HashSet<Point>> = HashMap::new();
+
+    grid.indexed_iter()
+        .filter(|(_, char)| char.is_alphanumeric())
+        .for_each(|(position, &char)| {
+            antennas.entry(char).or_default().insert(position.into());
+        });
+
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    for (_frequency, positions) in &antennas {
+        for &pos1 in positions {
+            for &pos2 in positions {
+                if pos1 == pos2 {
+                    continue; // Skip self-pairs
+                }
+
+                let offset = pos2 - pos1;
+
+                let mut multiplier = 1;
+                while let Some(antinode) = pos1
+                    .checked_add(offset * multiplier)
+                    .filter(|&antinode| grid.contains(antinode))
+                {
+                    // println!(
+                    //     "pos1: {:?}, pos2: {:?}, antinode: {:?}, mult: {:?}",
+                    //     pos1, pos2, antinode, multiplier
+                    // );
+
+                    antinodes.insert(antinode);
+                    multiplier += 1;
+                }
+            }
+        }
+    }
+
+    let mut new_grid = grid.clone();
+
+    for &antinode in &antinodes {
+        if let Some(cell) = new_grid.at_mut(antinode) {
+            *cell = '#';
+        }
+    }
+
+    // println!("antinodes: {:?}", antinodes);
+    // print_grid(&new_grid);
+
+    antinodes.len() as u32
+}
+
+// fn print_grid(grid: &Grid<char>) {
+//     for row in 0..grid.rows() {
+//         for col in 0..grid.cols() {
+//             print!("{}", grid.get(row, col).unwrap_or(&'.')); // Default to '.' if out of bounds
+//         }
+//         println!();
+//     }
+//     println!();
+// }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_count_antinodes() {
+        let input = &fixture(2024, 8, 0).input;
+        let grid = Day08::parse(input).unwrap();
+        assert_eq!(count_antinodes(&grid), 14);
+    }
+
+    #[test]
+    fn test_count_resonant_antinodes() {
+        let input = &fixture(2024, 8, 0).input;
+        let grid = Day08::parse(input).unwrap();
+        assert_eq!(count_resonant_antinodes(&grid), 34);
+    }
+}
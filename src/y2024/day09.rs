@@ -1,18 +1,58 @@
-use crate::{Solution, SolutionPair};
-use std::fs::read_to_string;
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day09.txt").expect("Day 9 input file should be present");
-    let sol1 = get_checksum(&input);
-    let sol2 = get_checksum_whole_file_defrag(&input);
+pub struct Day09;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Solver for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+    const NAME: &'static str = "Disk Fragmenter";
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_disk_map(input)
+    }
+
+    fn part1(dense_disk: &Self::Input<'_>) -> SolveResult {
+        let sol1 = get_checksum(dense_disk);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(dense_disk: &Self::Input<'_>) -> SolveResult {
+        let sol2 = get_checksum_whole_file_defrag(dense_disk);
+
+        Ok(Solution::from(sol2))
+    }
 }
 
-fn get_checksum(input: &str) -> u64 {
-    let dense_disk: Vec<u32> = input.chars().map(|c| c.to_digit(10).unwrap()).collect();
+inventory::submit! { DaySolver::new::<Day09>() }
+
+// One digit per block length, alternating between files and free space
+fn parse_disk_map(input: &str) -> Result<Vec<u32>, SolveError> {
+    let digits = input.trim();
+    if digits.is_empty() {
+        return Err(SolveError::parse(1, 1, "the disk map is empty"));
+    }
+    let dense_disk: Vec<u32> = digits
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).ok_or_else(|| {
+                SolveError::at(input, &digits[i..i + c.len_utf8()], "expected a digit")
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    if dense_disk.iter().all(|&length| length == 0) {
+        return Err(SolveError::parse(1, 1, "the disk map has no blocks"));
+    }
+
+    Ok(dense_disk)
+}
+
+fn get_checksum(dense_disk: &[u32]) -> u64 {
     let total_size: usize = dense_disk.iter().sum::<u32>() as usize;
     let mut disk: Vec<Option<u32>> = vec![None; total_size];
 
@@ -20,11 +60,9 @@ fn get_checksum(input: &str) -> u64 {
     let mut index = 0;
     let mut free = false;
 
-    for length in dense_disk {
+    for &length in dense_disk {
         if !free {
-            for i in index..index + length as usize {
-                disk[i] = Some(id);
-            }
+            disk[index..index + length as usize].fill(Some(id));
             id += 1;
         }
         index += length as usize;
@@ -52,8 +90,7 @@ fn get_checksum(input: &str) -> u64 {
         .sum()
 }
 
-fn get_checksum_whole_file_defrag(input: &str) -> u64 {
-    let dense_disk: Vec<u32> = input.chars().map(|c| c.to_digit(10).unwrap()).collect();
+fn get_checksum_whole_file_defrag(dense_disk: &[u32]) -> u64 {
     let total_size: usize = dense_disk.iter().sum::<u32>() as usize;
 
     let mut disk: Vec<Option<u32>> = vec![None; total_size];
@@ -62,11 +99,9 @@ fn get_checksum_whole_file_defrag(input: &str) -> u64 {
     let mut index = 0;
     let mut free = false;
 
-    for length in dense_disk {
+    for &length in dense_disk {
         if !free {
-            for i in index..index + length as usize {
-                disk[i] = Some(id);
-            }
+            disk[index..index + length as usize].fill(Some(id));
             id += 1;
         }
         index += length as usize;
@@ -112,8 +147,6 @@ fn get_checksum_whole_file_defrag(input: &str) -> u64 {
         }
     }
 
-    // println!("disk: {:?}", disk);
-
     disk.iter()
         .enumerate()
         .filter_map(|(i, &block)| block.map(|file_id| (i as u64) * file_id as u64))
@@ -123,24 +156,38 @@ fn get_checksum_whole_file_defrag(input: &str) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn test_input() -> &'static str {
-        r#"2333133121414131402"#
-    }
+    use crate::etc::examples::fixture;
 
     // input represents 00...111...2...333.44.5555.6666.777.888899
     // once files are moved
     // input becomes 0099811188827773336446555566..............
     #[test]
     fn test_get_checksum() {
-        let input = test_input();
-        assert_eq!(get_checksum(&input), 1928);
+        let input = &fixture(2024, 9, 0).input;
+        assert_eq!(get_checksum(&parse_disk_map(input).unwrap()), 1928);
+    }
+
+    #[test]
+    fn test_parse_disk_map_error() {
+        assert!(matches!(
+            parse_disk_map("2333x33"),
+            Err(SolveError::Parse {
+                line: 1,
+                column: 5,
+                ..
+            })
+        ));
+        assert!(parse_disk_map("\n").is_err());
+        assert!(parse_disk_map("0").is_err());
     }
 
     // 00992111777.44.333....5555.6666.....8888..
     #[test]
     fn test_get_checksum_whole_file_defrag() {
-        let input = test_input();
-        assert_eq!(get_checksum_whole_file_defrag(&input), 2858);
+        let input = &fixture(2024, 9, 0).input;
+        assert_eq!(
+            get_checksum_whole_file_defrag(&parse_disk_map(input).unwrap()),
+            2858
+        );
     }
 }
@@ -0,0 +1,103 @@
+use crate::etc::geometry::Point;
+use crate::etc::grid::{find_all, parse_digits, GridExt};
+use crate::etc::search::{all_shortest_paths, flood_fill};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use grid::*;
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+    const NAME: &'static str = "Hoof It";
+
+    // the height of each position
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_digits(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> SolveResult {
+        let sol1: usize = count_trailhead_scores(grid);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> SolveResult {
+        let sol2: usize = count_trailhead_ratings(grid);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day10>() }
+
+// The positions a hiking trail can continue to, exactly one higher than `position`
+fn uphill(grid: &Grid<u8>, position: Point) -> Vec<Point> {
+    let height = grid.at(position).map_or(0, |&height| height);
+    position
+        .neighbors4(grid)
+        .filter(|&neighbor| grid.at(neighbor) == Some(&(height + 1)))
+        .collect()
+}
+
+fn is_summit(grid: &Grid<u8>, position: Point) -> bool {
+    grid.at(position) == Some(&9)
+}
+
+// The score of a trailhead is the number of summits reachable from it
+fn count_trailhead_scores(grid: &Grid<u8>) -> usize {
+    find_all(grid, &0)
+        .map(|start| {
+            flood_fill(&start, |&position| uphill(grid, position))
+                .into_iter()
+                .filter(|&position| is_summit(grid, position))
+                .count()
+        })
+        .sum()
+}
+
+// The rating of a trailhead is the number of trails from it to any summit. Every trail climbs
+// one step at a time, so they are all shortest paths of 9 steps.
+fn count_trailhead_ratings(grid: &Grid<u8>) -> usize {
+    find_all(grid, &0)
+        .filter_map(|start| {
+            all_shortest_paths(
+                &start,
+                |&position| uphill(grid, position).into_iter().map(|next| (next, 1)),
+                |&position| is_summit(grid, position),
+            )
+        })
+        .map(|(trails, _): (Vec<Vec<Point>>, usize)| trails.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_count_trails_small() {
+        let input = &fixture(2024, 10, 1).input;
+        let grid = Day10::parse(input).unwrap();
+        assert_eq!(count_trailhead_scores(&grid), 4);
+    }
+
+    #[test]
+    fn test_count_trails() {
+        let input = &fixture(2024, 10, 0).input;
+        let grid = Day10::parse(input).unwrap();
+        assert_eq!(count_trailhead_scores(&grid), 36);
+    }
+
+    #[test]
+    fn test_count_trailhead_ratings() {
+        let input = &fixture(2024, 10, 0).input;
+        let grid = Day10::parse(input).unwrap();
+        assert_eq!(count_trailhead_ratings(&grid), 81);
+    }
+}
@@ -1,23 +1,42 @@
-use crate::{Solution, SolutionPair};
-use hashbrown::{HashMap, HashSet};
-use std::fs::read_to_string;
+use crate::etc::parse::{parse_all, spaced, unsigned};
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day11.txt").expect("Day 11 input file should be present");
-    let sol1 = count_stones(&input, 25);
-    let sol2 = count_stones(&input, 75);
+pub struct Day11;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Solver for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    const NAME: &'static str = "Plutonian Pebbles";
+
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_all(input, spaced(unsigned))
+    }
+
+    fn part1(stones: &Self::Input<'_>) -> SolveResult {
+        let sol1 = count_stones(stones, 25);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(stones: &Self::Input<'_>) -> SolveResult {
+        let sol2 = count_stones(stones, 75);
+
+        Ok(Solution::from(sol2))
+    }
 }
 
-fn count_stones(input: &str, splits: usize) -> u64 {
+inventory::submit! { DaySolver::new::<Day11>() }
+
+fn count_stones(initial_stones: &[u64], splits: usize) -> u64 {
     use hashbrown::HashMap;
 
     let mut stones: HashMap<u64, usize> = HashMap::new();
-    for num in input.split_whitespace() {
-        let stone = num.parse::<u64>().unwrap();
+    for &stone in initial_stones {
         *stones.entry(stone).or_insert(0) += 1;
     }
 
@@ -33,7 +52,7 @@ fn count_stones(input: &str, splits: usize) -> u64 {
             } else {
                 // Use logarithms to determine if the number of digits is even
                 let num_digits = ((stone as f64).log10().floor() as usize) + 1;
-                if num_digits % 2 == 0 {
+                if num_digits.is_multiple_of(2) {
                     // Rule 2: Even number of digits, split into two halves
                     let power = 10u64.pow((num_digits / 2) as u32);
                     let left = stone / power;
@@ -58,10 +77,14 @@ fn count_stones(input: &str, splits: usize) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::etc::examples::fixture;
+
     #[test]
     fn test_count_stones() {
-        assert_eq!(count_stones("125 17", 6), 22);
+        let stones = Day11::parse(&fixture(2024, 11, 0).input).unwrap();
+        assert_eq!(count_stones(&stones, 6), 22);
 
-        assert_eq!(count_stones("125 17", 25), 55312);
+        assert_eq!(count_stones(&stones, 25), 55312);
+        assert!(Day11::parse("125 x").is_err());
     }
 }
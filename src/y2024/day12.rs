@@ -0,0 +1,121 @@
+use crate::etc::geometry::{Direction, Point};
+use crate::etc::grid::{parse_grid, GridExt};
+use crate::etc::search::connected_components;
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+use grid::*;
+use hashbrown::HashSet;
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+    const NAME: &'static str = "Garden Groups";
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> SolveResult {
+        let sol1: usize = total_price_fencing(grid);
+
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> SolveResult {
+        let sol2: usize = total_price_fencing_sides(grid);
+
+        Ok(Solution::from(sol2))
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day12>() }
+
+// The regions of plants of the same type, as the positions they cover
+fn regions(grid: &Grid<char>) -> Vec<HashSet<Point>> {
+    connected_components(
+        grid.indexed_iter()
+            .map(|(position, _)| Point::from(position)),
+        |&position| get_valid_neighbors(position, grid),
+    )
+}
+
+fn get_valid_neighbors(position: Point, grid: &Grid<char>) -> Vec<Point> {
+    let plant_type = grid.at(position);
+    position
+        .neighbors4(grid)
+        .filter(|&neighbor| grid.at(neighbor) == plant_type)
+        .collect()
+}
+
+fn total_price_fencing(grid: &Grid<char>) -> usize {
+    regions(grid)
+        .iter()
+        .map(|area| {
+            // println!("area: {}, perimeter: {}", area.len(), count_perimeter(area));
+            area.len() * count_perimeter(area)
+        })
+        .sum()
+}
+
+fn total_price_fencing_sides(grid: &Grid<char>) -> usize {
+    regions(grid)
+        .iter()
+        .map(|area| {
+            // println!("area: {}, sides: {}", area.len(), count_sides(area));
+            area.len() * count_sides(area)
+        })
+        .sum()
+}
+
+// Every position of the region has a fence edge towards each neighbor outside of it
+fn count_perimeter(area: &HashSet<Point>) -> usize {
+    area.iter()
+        .flat_map(|&position| Direction::ALL.map(|direction| position.step(direction)))
+        .filter(|neighbor| !neighbor.is_some_and(|neighbor| area.contains(&neighbor)))
+        .count()
+}
+
+// A side is a run of fence edges facing the same direction, counted once at its first edge
+fn count_sides(area: &HashSet<Point>) -> usize {
+    let has_edge = |position: Point, direction: Direction| {
+        area.contains(&position)
+            && !position
+                .step(direction)
+                .is_some_and(|neighbor| area.contains(&neighbor))
+    };
+
+    area.iter()
+        .flat_map(|&position| Direction::ALL.map(|direction| (position, direction)))
+        .filter(|&(position, direction)| {
+            has_edge(position, direction)
+                && !position
+                    .step(direction.turn_left())
+                    .is_some_and(|previous| has_edge(previous, direction))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::examples::fixture;
+
+    #[test]
+    fn test_total_price_fencing() {
+        let input = &fixture(2024, 12, 0).input;
+        let grid = Day12::parse(input).unwrap();
+        assert_eq!(total_price_fencing(&grid), 1930);
+    }
+
+    #[test]
+    fn test_total_price_fencing_sides() {
+        let input = &fixture(2024, 12, 0).input;
+        let grid = Day12::parse(input).unwrap();
+        assert_eq!(total_price_fencing_sides(&grid), 1206);
+    }
+}
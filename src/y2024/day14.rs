@@ -0,0 +1,33 @@
+use crate::etc::cli::Part;
+use crate::etc::solver::{DaySolver, Solver};
+use crate::{Solution, SolveError, SolveResult};
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+    const NAME: &'static str = "Restroom Redoubt";
+    // List the parts here as they get a solution
+    const PARTS: &'static [Part] = &[];
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> SolveResult {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> SolveResult {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+}
+
+inventory::submit! { DaySolver::new::<Day14>() }